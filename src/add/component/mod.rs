@@ -0,0 +1,117 @@
+use std::path::{Path, PathBuf};
+
+use crate::add::{SourceEdits, identifier, insert_after_last, read_source};
+use crate::error::{FluxorCliError, Result};
+
+/// Options of `fluxor add component`.
+///
+/// Emits the pattern of the `fluxor-template` components: a `const` cans template with a
+/// `{{param}}` placeholder per parameter and a function rendering it with `do_html!`,
+/// re-exported from `src/components/mod.rs`.
+#[derive(Debug, Clone)]
+pub struct AddComponent<'a> {
+    /// The component name, e.g. `card`.
+    pub name: &'a str,
+    /// The `&str` parameters of the component function, one placeholder each.
+    pub params: &'a [String],
+}
+
+impl AddComponent<'_> {
+    /// Adds the component to the project at `root`.
+    pub fn add(&self, root: &Path) -> Result<()> {
+        let name = identifier(self.name)?;
+
+        let mut params: Vec<String> = Vec::new();
+        for param in self.params {
+            let converted = identifier(param)?;
+            if params.contains(&converted) {
+                return Err(FluxorCliError::InvalidName(param.to_string()));
+            }
+            params.push(converted);
+        }
+
+        let mod_path = PathBuf::from("src/components/mod.rs");
+        let components = read_source(root, &mod_path, "the components module of the `fluxor-template` layout")?;
+
+        let component_path = PathBuf::from(format!("src/components/{}.rs", name));
+        if root.join(&component_path).exists() || root.join(format!("src/components/{}", name)).exists() {
+            return Err(FluxorCliError::WouldOverwrite(vec![component_path]));
+        }
+
+        let components = insert_after_last(
+            &components,
+            |line| line.starts_with("mod ") || line.starts_with("pub mod "),
+            &[format!("mod {};", name)],
+        );
+        let components = insert_after_last(
+            &components,
+            |line| line.starts_with("pub use "),
+            &[format!("pub use {}::{};", name, name)],
+        );
+
+        let mut edits = SourceEdits::default();
+        edits.create(&component_path, component_source(&name, &params));
+        edits.update(&mod_path, components);
+        edits.apply(root)?;
+
+        println!("Added component {}({}).", name, params.join(", "));
+
+        Ok(())
+    }
+}
+
+fn component_source(name: &str, params: &[String]) -> String {
+    let template = name.to_uppercase();
+    let class = name.replace('_', "-");
+
+    let mut markup = format!("<div class=\"{}\">", class);
+    for param in params {
+        markup.push_str(&format!(
+            "\n                <div class=\"{}__{}\">{{{{{}}}}}</div>",
+            class,
+            param.replace('_', "-"),
+            param
+        ));
+    }
+    markup.push_str("\n            </div>");
+
+    let signature = params
+        .iter()
+        .map(|param| format!("{}: &str", param))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let arguments: String = if params.is_empty() {
+        ",".to_string()
+    } else {
+        params.iter().map(|param| format!(", {}={}", param, param)).collect()
+    };
+
+    format!(
+        "use fluxor::cans::content::do_html;\n\nconst {template}: &str = r##\"{markup}\"##;\n\npub fn {name}({signature}) -> String {{\n    do_html!({template}{arguments})\n}}\n",
+        template = template,
+        markup = markup,
+        name = name,
+        signature = signature,
+        arguments = arguments,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn add(name: &str, params: &[&str]) -> Result<()> {
+        let params: Vec<String> = params.iter().map(|param| param.to_string()).collect();
+        // Names are checked before the project is read
+        AddComponent { name, params: &params }.add(Path::new("/nonexistent"))
+    }
+
+    #[test]
+    fn keywords_are_rejected_as_names_and_params() {
+        assert!(matches!(add("type", &[]), Err(FluxorCliError::InvalidName(name)) if name == "type"));
+        assert!(matches!(add("card", &["title", "Self"]), Err(FluxorCliError::InvalidName(name)) if name == "Self"));
+        assert!(matches!(add("card", &["title", "title"]), Err(FluxorCliError::InvalidName(name)) if name == "title"));
+        assert!(matches!(add("!", &[]), Err(FluxorCliError::InvalidName(_))));
+        assert!(matches!(add("card", &["title"]), Err(FluxorCliError::ProjectLayout { .. })));
+    }
+}
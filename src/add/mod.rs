@@ -0,0 +1,100 @@
+pub mod route;
+pub mod page;
+pub mod component;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{FluxorCliError, Result};
+use crate::sink::{DiskSink, ProjectSink};
+use crate::utils::to_crate_name;
+
+pub use component::AddComponent;
+pub use page::AddPage;
+pub use route::{AddRoute, RouteKind};
+
+/// Finds the root of the Fluxor project containing `start`: the closest directory with a Cargo.toml.
+pub fn find_project_root(start: &Path) -> Result<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| dir.join("Cargo.toml").is_file())
+        .map(Path::to_path_buf)
+        .ok_or_else(|| FluxorCliError::ProjectLayout {
+            path: start.to_path_buf(),
+            message: "no Cargo.toml found in this directory or any parent".to_string(),
+        })
+}
+
+/// The source files created or updated by a `fluxor add` generator.
+///
+/// Every edit is computed before anything is written, so a generator that finds an unexpected
+/// layout half way through leaves the project untouched.
+#[derive(Debug, Default)]
+pub struct SourceEdits {
+    files: Vec<(PathBuf, String, bool)>,
+}
+
+impl SourceEdits {
+    /// Records a new file at `path`, relative to the project root.
+    pub fn create(&mut self, path: impl Into<PathBuf>, contents: String) {
+        self.files.push((path.into(), contents, true));
+    }
+
+    /// Records new contents for the existing file at `path`, relative to the project root.
+    pub fn update(&mut self, path: impl Into<PathBuf>, contents: String) {
+        self.files.push((path.into(), contents, false));
+    }
+
+    /// Writes every recorded file below `root` and reports what changed.
+    pub fn apply(self, root: &Path) -> Result<()> {
+        let mut sink = DiskSink::new(root);
+
+        for (path, contents, created) in &self.files {
+            if let Some(parent) = path.parent() {
+                sink.create_dir(parent)?;
+            }
+            sink.write_file(path, contents.as_bytes())?;
+            println!("{} {}", if *created { "Created" } else { "Updated" }, path.display());
+        }
+
+        Ok(())
+    }
+}
+
+/// Converts `input` with [`to_crate_name`] and checks that the result is a Rust identifier,
+/// rejecting keywords such as `type`.
+pub(crate) fn identifier(input: &str) -> Result<String> {
+    let name = to_crate_name(input);
+    match syn::parse_str::<syn::Ident>(&name) {
+        Ok(_) => Ok(name),
+        Err(_) => Err(FluxorCliError::InvalidName(input.to_string())),
+    }
+}
+
+/// Reads a project file, reporting a missing file as a layout mismatch.
+pub(crate) fn read_source(root: &Path, path: &Path, expected: &str) -> Result<String> {
+    fs::read_to_string(root.join(path)).map_err(|_| FluxorCliError::ProjectLayout {
+        path: path.to_path_buf(),
+        message: format!("expected {}", expected),
+    })
+}
+
+/// Inserts `new_lines` after the last line matching `anchor`, or at the end if none matches.
+///
+/// The text is split and joined on `\n`, so a missing trailing newline stays missing.
+pub(crate) fn insert_after_last(source: &str, anchor: impl Fn(&str) -> bool, new_lines: &[String]) -> String {
+    let mut lines: Vec<&str> = source.split('\n').collect();
+
+    let index = match lines.iter().rposition(|line| anchor(line)) {
+        Some(index) => index + 1,
+        // Keep a trailing empty line (the final newline) after the inserted lines
+        None if lines.last() == Some(&"") => lines.len() - 1,
+        None => lines.len(),
+    };
+
+    for (offset, line) in new_lines.iter().enumerate() {
+        lines.insert(index + offset, line);
+    }
+
+    lines.join("\n")
+}
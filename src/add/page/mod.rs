@@ -0,0 +1,210 @@
+use std::path::{Path, PathBuf};
+
+use crate::add::route::{RouteHandler, RouteKind, parse_route_path};
+use crate::add::{SourceEdits, identifier, read_source};
+use crate::error::{FluxorCliError, Result};
+
+/// Options of `fluxor add page`.
+///
+/// Follows the `fluxor-template` layout: the page is rendered with the `layout(...)` component,
+/// added to `src/routes/pages`, registered in `setup_routes` and linked from both the desktop
+/// and the mobile navigation in `src/components/nav.rs`.
+#[derive(Debug, Clone)]
+pub struct AddPage<'a> {
+    /// The page name, e.g. `pricing`.
+    pub name: &'a str,
+    /// The route path (default: `/<name>`).
+    pub path: Option<&'a str>,
+    /// The title used in the navigation and the page heading (default: derived from the name).
+    pub title: Option<&'a str>,
+}
+
+impl AddPage<'_> {
+    /// Adds the page to the project at `root`.
+    pub fn add(&self, root: &Path) -> Result<()> {
+        let module = page_module(self.name)?;
+
+        let handler = format!("{}_page", module);
+        let path = match self.path {
+            Some(path) => path.to_string(),
+            None => default_path(&module),
+        };
+        parse_route_path(&path)?;
+        let title = match self.title {
+            Some(title) => title.to_string(),
+            None => title_case(&module),
+        };
+
+        // The page needs the layout component and the navigation of the fluxor-template layout
+        let components_path = PathBuf::from("src/components/mod.rs");
+        let components = read_source(root, &components_path, "the components module of the `fluxor-template` layout")?;
+        if !components.lines().any(|line| line.trim_start().starts_with("pub use layout::") && line.contains("layout")) {
+            return Err(FluxorCliError::ProjectLayout {
+                path: components_path,
+                message: "the `layout` component is not exported".to_string(),
+            });
+        }
+
+        let page_path = PathBuf::from(format!("src/routes/pages/{}.rs", module));
+        if root.join(&page_path).exists() || root.join(format!("src/routes/pages/{}", module)).exists() {
+            return Err(FluxorCliError::WouldOverwrite(vec![page_path]));
+        }
+
+        let nav_path = PathBuf::from("src/components/nav.rs");
+        let nav = read_source(root, &nav_path, "the navigation of the `fluxor-template` layout")?;
+        let link = format!("<a href=\"{}\" class=\"spinner-on-click header__link\">{}</a>", path, title);
+        let nav = ["DESKTOP_NAV", "MOBILE_NAV"]
+            .iter()
+            .try_fold(nav, |nav, name| add_nav_link(&nav, name, &link))
+            .map_err(|message| FluxorCliError::ProjectLayout {
+                path: nav_path.clone(),
+                message,
+            })?;
+
+        let mut edits = SourceEdits::default();
+        let source = page_function(&module, &handler, &title);
+
+        RouteHandler {
+            kind: RouteKind::Pages,
+            method: "GET",
+            path: &path,
+            module: &module,
+            name: &handler,
+            source: &source,
+        }
+        .plan(root, &mut edits)?;
+        edits.update(nav_path, nav);
+
+        edits.apply(root)?;
+
+        println!("Added page '{}' at GET {}.", title, path);
+
+        Ok(())
+    }
+}
+
+/// The module of a page name, with words separated by `_` so `About Us` becomes `about_us`.
+fn page_module(name: &str) -> Result<String> {
+    identifier(&name.split_whitespace().collect::<Vec<_>>().join("_"))
+        .map_err(|_| FluxorCliError::InvalidName(name.to_string()))
+}
+
+/// `about_us` is served at `/about-us`, and `_404` at `/404`.
+fn default_path(module: &str) -> String {
+    format!("/{}", module.trim_start_matches('_').replace('_', "-"))
+}
+
+/// `contact_us` becomes `Contact Us`.
+fn title_case(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The page module without its `use fluxor::prelude::*;` header, which is added with the module.
+fn page_function(module: &str, handler: &str, title: &str) -> String {
+    let content = format!("MAIN_{}_CONTENT", module.to_uppercase());
+
+    format!(
+        r####"use crate::components::*;
+
+const {content}: &str = r##"<!-- {title} Block -->
+            <section class="container">
+                <h1>{title}</h1>
+                <p>Welcome to the {title} page.</p>
+            </section>"##;
+
+const SOURCES: &str = r##"<link rel="stylesheet" href="/css/styles.css">
+    <script defer src="/js/alpine.min.js"></script>"##;
+
+pub fn {handler}(_req: Req, _params: Params) -> Reply {{
+    boxed(async move {{
+        let content = layout(
+            "Fluxor — {lower} page",
+            "Fluxor is a versatile Rust web framework designed for data science and computing science applications.",
+            "async, data-science, fluxor, framework, web, {module}",
+            &do_html!(SOURCES,),
+            &do_html!({content},)
+        );
+
+        Ok(Response::builder()
+            .header("Content-Type", "text/html; charset=UTF-8")
+            .body(Body::from(content))
+            .unwrap())
+    }})
+}}
+"####,
+        content = content,
+        title = title,
+        lower = title.to_lowercase(),
+        module = module,
+        handler = handler,
+    )
+}
+
+/// Adds `link` after the last internal `header__link` of the `name` constant in nav.rs.
+fn add_nav_link(nav: &str, name: &str, link: &str) -> std::result::Result<String, String> {
+    let mut lines: Vec<String> = nav.split('\n').map(str::to_string).collect();
+
+    let start = lines
+        .iter()
+        .position(|line| line.contains(&format!("const {}:", name)))
+        .ok_or_else(|| format!("`{}` is not defined", name))?;
+    let end = lines[start..]
+        .iter()
+        .position(|line| line.trim_end().ends_with("\"##;"))
+        .map(|offset| start + offset)
+        .ok_or_else(|| format!("the end of `{}` was not found", name))?;
+
+    if lines[start..=end].iter().any(|line| line.contains(link)) {
+        return Ok(nav.to_string());
+    }
+
+    let anchor = (start..=end)
+        .rev()
+        .find(|&index| {
+            let line = lines[index].trim_start();
+            line.starts_with("<a href=\"/") && line.contains("header__link")
+        })
+        .ok_or_else(|| format!("`{}` has no internal `header__link` to add the page next to", name))?;
+
+    let indent = lines[anchor][..lines[anchor].len() - lines[anchor].trim_start().len()].to_string();
+    lines.insert(anchor + 1, format!("{}{}", indent, link));
+
+    Ok(lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn page_names_become_modules_paths_and_titles() {
+        let module = page_module("About Us").unwrap();
+        assert_eq!(module, "about_us");
+        assert_eq!(default_path(&module), "/about-us");
+        assert_eq!(title_case(&module), "About Us");
+
+        let module = page_module("404").unwrap();
+        assert_eq!(module, "_404");
+        assert_eq!(default_path(&module), "/404");
+        assert_eq!(title_case(&module), "404");
+    }
+
+    #[test]
+    fn keywords_are_rejected() {
+        assert!(matches!(page_module("type"), Err(FluxorCliError::InvalidName(name)) if name == "type"));
+        assert!(matches!(page_module("  "), Err(FluxorCliError::InvalidName(_))));
+
+        let page = AddPage { name: "mod", path: None, title: None };
+        assert!(matches!(page.add(Path::new("/nonexistent")), Err(FluxorCliError::InvalidName(name)) if name == "mod"));
+    }
+}
@@ -0,0 +1,573 @@
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use clap::ValueEnum;
+use syn::spanned::Spanned;
+use syn::{Expr, ExprLit, FnArg, Item, ItemFn, ItemMod, ItemUse, Lit, Pat, Stmt, UseTree};
+
+use crate::add::{SourceEdits, insert_after_last, read_source};
+use crate::error::{FluxorCliError, Result};
+use crate::utils::to_crate_name;
+
+/// The HTTP methods exported by `fluxor::prelude`.
+pub const METHODS: &[&str] = &["GET", "POST", "PUT", "DELETE", "PATCH", "HEAD", "OPTIONS", "CONNECT", "TRACE"];
+
+/// Where a route handler lives: `src/routes/api` or `src/routes/pages`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RouteKind {
+    Api,
+    Pages,
+}
+
+impl RouteKind {
+    /// The module below `src/routes` holding handlers of this kind.
+    pub fn module(self) -> &'static str {
+        match self {
+            RouteKind::Api => "api",
+            RouteKind::Pages => "pages",
+        }
+    }
+
+    /// Whether a route path belongs next to the routes of this kind in `setup_routes`.
+    fn groups_with(self, path: &str) -> bool {
+        match self {
+            RouteKind::Api => path.starts_with("/api"),
+            RouteKind::Pages => !path.starts_with("/api") && path != "/http-client",
+        }
+    }
+}
+
+/// Options of `fluxor add route`.
+///
+/// Follows the layout of the `routes-project` and `fluxor-template` examples: the handler is
+/// added to `src/routes/<kind>/<module>.rs`, declared in `src/routes/<kind>/mod.rs` (either as
+/// `pub mod <module>;` or as `mod <module>;` plus `pub use <module>::<handler>;`, whichever
+/// that file already uses) and registered in `setup_routes` in `src/routes/mod.rs`.
+#[derive(Debug, Clone)]
+pub struct AddRoute<'a> {
+    /// The HTTP method, e.g. `GET`.
+    pub method: &'a str,
+    /// The route path, e.g. `/api/users/<id>`.
+    pub path: &'a str,
+    pub kind: RouteKind,
+    /// The handler function name; derived from the method and path if `None`.
+    pub handler: Option<&'a str>,
+}
+
+/// A parsed route path.
+pub(crate) struct RoutePath {
+    /// The static segments, e.g. `["api", "users"]`.
+    segments: Vec<String>,
+    /// The `<param>` names, e.g. `["id"]`.
+    params: Vec<String>,
+}
+
+impl AddRoute<'_> {
+    /// Adds the route to the project at `root`.
+    pub fn add(&self, root: &Path) -> Result<()> {
+        let method = self.method.to_uppercase();
+        if !METHODS.contains(&method.as_str()) {
+            return Err(FluxorCliError::InvalidRoute(format!(
+                "unknown method '{}'; expected one of {}",
+                self.method,
+                METHODS.join(", ")
+            )));
+        }
+
+        let route = parse_route_path(self.path)?;
+
+        // `/api/users/<id>` lives in `api/users.rs`, `/about` in `pages/about.rs`
+        let module = route
+            .segments
+            .iter()
+            .find(|segment| !(self.kind == RouteKind::Api && segment.as_str() == "api"))
+            .map(|segment| to_crate_name(segment))
+            .unwrap_or_else(|| "index".to_string());
+
+        let handler = match self.handler {
+            Some(handler) => to_crate_name(handler),
+            None => default_handler_name(&method, &route),
+        };
+        if handler.is_empty() {
+            return Err(FluxorCliError::InvalidRoute(format!("'{}' is not a valid handler name", self.handler.unwrap_or_default())));
+        }
+
+        let mut edits = SourceEdits::default();
+        let handler_source = handler_function(self.kind, &handler, &method, self.path, &route);
+        let handler_expression = RouteHandler {
+            kind: self.kind,
+            method: &method,
+            path: self.path,
+            module: &module,
+            name: &handler,
+            source: &handler_source,
+        }
+        .plan(root, &mut edits)?;
+
+        edits.apply(root)?;
+
+        println!("Added route {} {} -> {}.", method, self.path, handler_expression);
+
+        Ok(())
+    }
+}
+
+/// A handler function to add to a project, shared by `fluxor add route` and `fluxor add page`.
+pub(crate) struct RouteHandler<'a> {
+    pub kind: RouteKind,
+    pub method: &'a str,
+    pub path: &'a str,
+    /// The module below `src/routes/<kind>`.
+    pub module: &'a str,
+    pub name: &'a str,
+    /// The source of the handler function.
+    pub source: &'a str,
+}
+
+impl RouteHandler<'_> {
+    /// Records the edits that add the handler to its module and register it in `setup_routes`,
+    /// returning the handler expression used in the route.
+    pub(crate) fn plan(&self, root: &Path, edits: &mut SourceEdits) -> Result<String> {
+        let RouteHandler { kind: route_kind, method, path, module, name: handler, source: handler_source } = *self;
+        let kind = route_kind.module();
+        let routes_path = PathBuf::from("src/routes/mod.rs");
+        let kind_path = PathBuf::from(format!("src/routes/{}/mod.rs", kind));
+        let routes_source = read_source(root, &routes_path, "the routes module of the `routes-project` or `fluxor-template` layout")?;
+        let kind_source = read_source(root, &kind_path, &format!("a `{}` routes module", kind))?;
+
+        let layout_error = |path: &Path, message: String| FluxorCliError::ProjectLayout {
+            path: path.to_path_buf(),
+            message,
+        };
+
+        if !declares_module(&routes_source, kind) {
+            return Err(layout_error(&routes_path, format!("`mod {};` is not declared", kind)));
+        }
+
+        // The handler module
+        let module_rs = PathBuf::from(format!("src/routes/{}/{}.rs", kind, module));
+        let module_dir_rs = PathBuf::from(format!("src/routes/{}/{}/mod.rs", kind, module));
+
+        let existing_module = [&module_rs, &module_dir_rs]
+            .into_iter()
+            .find(|path| root.join(path).is_file());
+
+        match existing_module {
+            Some(path) => {
+                let source = read_source(root, path, "a handler module")?;
+                if source.contains(&format!("fn {}(", handler)) {
+                    return Err(FluxorCliError::InvalidRoute(format!(
+                        "handler `{}` already exists in {}",
+                        handler,
+                        path.display()
+                    )));
+                }
+                edits.update(path, format!("{}\n\n{}", source.trim_end(), handler_source));
+            }
+            None => edits.create(&module_rs, format!("use fluxor::prelude::*;\n\n{}", handler_source)),
+        }
+
+        // The `<kind>/mod.rs` declarations, following the convention the file already uses
+        let reexports = kind_source.lines().any(|line| line.trim_start().starts_with("pub use "));
+        let mut kind_updated = kind_source.clone();
+
+        if reexports {
+            if !declares_module(&kind_updated, module) {
+                kind_updated = insert_after_last(&kind_updated, is_mod_line, &[format!("mod {};", module)]);
+            }
+            kind_updated = insert_after_last(
+                &kind_updated,
+                |line| line.trim_start().starts_with("pub use "),
+                &[format!("pub use {}::{};", module, handler)],
+            );
+        } else if !declares_module(&kind_updated, module) {
+            kind_updated = insert_after_last(&kind_updated, is_mod_line, &[format!("pub mod {};", module)]);
+        }
+
+        if kind_updated != kind_source {
+            edits.update(&kind_path, kind_updated);
+        }
+
+        // The `setup_routes` registration
+        let (handler_expression, routes_source) = if reexports {
+            match import_handler(&routes_source, kind, handler) {
+                Some(updated) => (handler.to_string(), updated),
+                None => (format!("{}::{}", kind, handler), routes_source),
+            }
+        } else {
+            (format!("{}::{}::{}", kind, module, handler), routes_source)
+        };
+
+        let routes_updated = register_route(&routes_source, route_kind, method, path, &handler_expression)
+            .map_err(|message| layout_error(&routes_path, message))?;
+        edits.update(&routes_path, routes_updated);
+
+        Ok(handler_expression)
+    }
+}
+
+/// Parses `/api/users/<id>` into its static segments and parameters.
+pub(crate) fn parse_route_path(path: &str) -> Result<RoutePath> {
+    let invalid = |message: &str| FluxorCliError::InvalidRoute(format!("'{}' {}", path, message));
+
+    if !path.starts_with('/') {
+        return Err(invalid("must start with '/'"));
+    }
+
+    let mut route = RoutePath {
+        segments: Vec::new(),
+        params: Vec::new(),
+    };
+
+    for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+        if let Some(param) = segment.strip_prefix('<').and_then(|segment| segment.strip_suffix('>')) {
+            if param.is_empty() || !param.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err(invalid("has an invalid `<param>` segment"));
+            }
+            route.params.push(param.to_string());
+        } else if segment.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')) {
+            route.segments.push(segment.to_string());
+        } else {
+            return Err(invalid("contains characters that are not allowed in a route segment"));
+        }
+    }
+
+    Ok(route)
+}
+
+/// `GET /api/users/<id>` becomes `get_users_by_id`, `GET /` becomes `get_index`.
+fn default_handler_name(method: &str, route: &RoutePath) -> String {
+    let mut name = method.to_lowercase();
+
+    let segments: Vec<&String> = route.segments.iter().filter(|segment| segment.as_str() != "api").collect();
+    if segments.is_empty() {
+        name.push_str("_index");
+    }
+    for segment in segments {
+        name.push('_');
+        name.push_str(&to_crate_name(segment));
+    }
+    for param in &route.params {
+        name.push_str("_by_");
+        name.push_str(param);
+    }
+
+    name
+}
+
+fn handler_function(kind: RouteKind, handler: &str, method: &str, path: &str, route: &RoutePath) -> String {
+    let params_argument = if route.params.is_empty() { "_params" } else { "params" };
+    let mut function = format!(
+        "pub fn {}(_req: Req, {}: Params) -> Reply {{\n    // app.route({}, \"{}\", {});\n",
+        handler, params_argument, method, path, handler
+    );
+
+    for param in &route.params {
+        function.push_str(&format!(
+            "    let {} = params.extra.get(\"{}\").cloned().unwrap_or_default();\n",
+            param, param
+        ));
+    }
+    if !route.params.is_empty() {
+        function.push('\n');
+    }
+
+    // Parameters are interpolated with inline `format!` arguments, e.g. `{id}`
+    let (content_type, text) = match kind {
+        RouteKind::Api => {
+            let mut fields = vec![format!("\"route\": \"{} {}\"", method, path)];
+            fields.extend(route.params.iter().map(|param| format!("\"{}\": \"{{{}}}\"", param, param)));
+            ("application/json", format!("{{{}}}", fields.join(", ")))
+        }
+        RouteKind::Pages => {
+            let title = route.segments.last().map(String::as_str).unwrap_or("Home");
+            let mut html = format!("<h1>{}</h1>", title);
+            for param in &route.params {
+                html.push_str(&format!("<p>{}: {{{}}}</p>", param, param));
+            }
+            ("text/html; charset=UTF-8", html)
+        }
+    };
+
+    let body = if route.params.is_empty() {
+        format!("String::from(r#\"{}\"#)", text)
+    } else {
+        // Escape the literal JSON braces, keeping the `{param}` arguments
+        let mut escaped = text.replace('{', "{{").replace('}', "}}");
+        for param in &route.params {
+            escaped = escaped.replace(&format!("{{{{{}}}}}", param), &format!("{{{}}}", param));
+        }
+        format!("format!(r#\"{}\"#)", escaped)
+    };
+
+    function.push_str(&format!(
+        "    boxed(async move {{\n        let response = {};\n\n        Ok(Response::builder()\n            .header(\"Content-Type\", \"{}\")\n            .body(Body::from(response))\n            .unwrap())\n    }})\n}}\n",
+        body, content_type
+    ));
+
+    function
+}
+
+fn is_mod_line(line: &str) -> bool {
+    let line = line.trim_start();
+    line.starts_with("mod ") || line.starts_with("pub mod ")
+}
+
+/// Whether `source` declares `mod name;` or `pub mod name;`.
+fn declares_module(source: &str, name: &str) -> bool {
+    source.lines().any(|line| {
+        let line = line.trim();
+        line == format!("mod {};", name) || line == format!("pub mod {};", name)
+    })
+}
+
+/// Adds `handler` to the `use <kind>::...;` import of `source`, e.g. `use api::{hello_world, msg};`.
+///
+/// Returns `source` unchanged if the handler is already imported, and `None` if there is no such import.
+fn import_handler(source: &str, kind: &str, handler: &str) -> Option<String> {
+    let file = syn::parse_file(source).ok()?;
+    let tree = file.items.iter().find_map(|item| match item {
+        Item::Use(ItemUse { leading_colon: None, tree: UseTree::Path(path), .. }) if path.ident == kind => Some(&*path.tree),
+        _ => None,
+    })?;
+
+    let imports = |tree: &UseTree| match tree {
+        UseTree::Name(name) => name.ident == handler,
+        UseTree::Rename(rename) => rename.rename == handler,
+        UseTree::Glob(_) => true,
+        _ => false,
+    };
+    let mut updated = source.to_string();
+
+    match tree {
+        UseTree::Group(group) => {
+            if group.items.iter().any(imports) {
+                return Some(updated);
+            }
+
+            let close = group.brace_token.span.close().byte_range().start;
+            match group.items.last() {
+                None => updated.insert_str(close, handler),
+                // A group closed on a line of its own gets a new line before it
+                Some(last) if source[last.span().byte_range().end..close].contains('\n') => {
+                    let last_end = last.span().byte_range().end;
+                    let line = line_start(source, close);
+                    updated.insert_str(line, &format!("{}{},\n", line_indent(source, last_end), handler));
+                    if !group.items.trailing_punct() {
+                        updated.insert(last_end, ',');
+                    }
+                }
+                Some(last) => updated.insert_str(last.span().byte_range().end, &format!(", {}", handler)),
+            }
+        }
+        tree if imports(tree) => {}
+        tree => {
+            let range = tree.span().byte_range();
+            let import = format!("{{{}, {}}}", &source[range.clone()], handler);
+            updated.replace_range(range, &import);
+        }
+    }
+
+    Some(updated)
+}
+
+/// Inserts `app.route(METHOD, "path", handler);` into `setup_routes`, next to the routes of the same kind.
+///
+/// The function and its route calls are found with `syn`, so the formatting of `setup_routes` and
+/// the items around it do not matter.
+fn register_route(source: &str, kind: RouteKind, method: &str, path: &str, handler: &str) -> std::result::Result<String, String> {
+    let file = syn::parse_file(source).map_err(|e| format!("line {}: {}", e.span().start().line, e))?;
+    let function = find_setup_routes(&file.items).ok_or_else(|| "no `setup_routes` function found".to_string())?;
+    let app = match function.sig.inputs.first() {
+        Some(FnArg::Typed(argument)) => match &*argument.pat {
+            Pat::Ident(pattern) => pattern.ident.to_string(),
+            _ => return Err("the `app` parameter of `setup_routes` is not a plain name".to_string()),
+        },
+        _ => return Err("`setup_routes` takes no `app` parameter".to_string()),
+    };
+
+    // The `app.route(METHOD, "path", handler);` statements, with the byte range of each
+    let routes: Vec<(String, String, Range<usize>)> = function
+        .block
+        .stmts
+        .iter()
+        .filter_map(|statement| {
+            let Stmt::Expr(Expr::MethodCall(call), Some(semicolon)) = statement else {
+                return None;
+            };
+            let Expr::Path(receiver) = &*call.receiver else {
+                return None;
+            };
+            if call.method != "route" || !receiver.path.is_ident(&app) {
+                return None;
+            }
+
+            let route_method = match call.args.first()? {
+                Expr::Path(method) => method.path.segments.last()?.ident.to_string(),
+                _ => return None,
+            };
+            let route_path = match call.args.iter().nth(1)? {
+                Expr::Lit(ExprLit { lit: Lit::Str(path), .. }) => path.value(),
+                _ => return None,
+            };
+            Some((route_method, route_path, receiver.span().byte_range().start..semicolon.span.byte_range().end))
+        })
+        .collect();
+
+    if routes.iter().any(|(route_method, route_path, _)| route_method == method && route_path == path) {
+        return Err(format!("the route {} {} is already registered", method, path));
+    }
+
+    let new_route = format!("{}.route({}, \"{}\", {});", app, method, path, handler);
+    let mut updated = source.to_string();
+
+    let anchor = routes
+        .iter()
+        .rev()
+        .find(|(_, route_path, _)| kind.groups_with(route_path))
+        .or(routes.last());
+
+    match anchor {
+        // On the line after the route, keeping a trailing comment on it
+        Some((_, _, range)) => {
+            let line_end = source[range.end..].find('\n').map_or(source.len(), |offset| range.end + offset);
+            updated.insert_str(line_end, &format!("\n{}{}", line_indent(source, range.start), new_route));
+        }
+        None => {
+            let close = function.block.brace_token.span.close().byte_range().start;
+            let line = line_start(source, close);
+            let indent = line_indent(source, close);
+            if source[line..close].trim().is_empty() {
+                updated.insert_str(line, &format!("{}    {}\n", indent, new_route));
+            } else {
+                updated.insert_str(close, &format!("\n{}    {}\n{}", indent, new_route, indent));
+            }
+        }
+    }
+
+    Ok(updated)
+}
+
+/// Finds `fn setup_routes` among `items`, including inline modules.
+fn find_setup_routes(items: &[Item]) -> Option<&ItemFn> {
+    items.iter().find_map(|item| match item {
+        Item::Fn(function) if function.sig.ident == "setup_routes" => Some(function),
+        Item::Mod(ItemMod { content: Some((_, items)), .. }) => find_setup_routes(items),
+        _ => None,
+    })
+}
+
+/// The byte offset where the line containing `offset` starts.
+fn line_start(source: &str, offset: usize) -> usize {
+    source[..offset].rfind('\n').map_or(0, |newline| newline + 1)
+}
+
+/// The leading whitespace of the line containing `offset`.
+fn line_indent(source: &str, offset: usize) -> &str {
+    let line = &source[line_start(source, offset)..];
+    &line[..line.len() - line.trim_start().len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn routes_are_registered_next_to_their_kind_in_formatted_sources() {
+        let source = r#"use fluxor::prelude::*;
+
+pub fn setup_routes(
+    app: &mut Fluxor,
+) {
+    app.route(GET, "/", pages::home);
+    app.route(
+        GET,
+        "/api/a-route-with-a-very-long-path/that-rustfmt-splits-over-lines",
+        api::long_route,
+    );
+    app.route(GET, "/about", pages::about); // the about page
+}
+
+pub fn other() {
+    if true {
+        println!("not a route");
+    }
+}
+"#;
+
+        assert_eq!(
+            register_route(source, RouteKind::Api, "POST", "/api/users", "api::create_user").unwrap(),
+            source.replace(
+                "        api::long_route,\n    );\n",
+                "        api::long_route,\n    );\n    app.route(POST, \"/api/users\", api::create_user);\n"
+            )
+        );
+        assert_eq!(
+            register_route(source, RouteKind::Pages, "GET", "/pricing", "pages::pricing").unwrap(),
+            source.replace(
+                "// the about page\n",
+                "// the about page\n    app.route(GET, \"/pricing\", pages::pricing);\n"
+            )
+        );
+    }
+
+    #[test]
+    fn registered_routes_are_rejected_however_they_are_formatted() {
+        let source = "fn setup_routes(server: &mut Fluxor) {\n    server.route(\n        GET,\n        \"/api/users\",\n        api::users,\n    );\n}\n";
+
+        assert_eq!(
+            register_route(source, RouteKind::Api, "GET", "/api/users", "api::users"),
+            Err("the route GET /api/users is already registered".to_string())
+        );
+        assert!(register_route(source, RouteKind::Api, "POST", "/api/users", "api::create_user").is_ok());
+    }
+
+    #[test]
+    fn the_first_route_goes_at_the_end_of_setup_routes() {
+        let source = "fn setup_routes(app: &mut Fluxor) {\n    let _unused = 1;\n}\n\nfn after() {\n}\n";
+        assert_eq!(
+            register_route(source, RouteKind::Api, "GET", "/api", "api::index").unwrap(),
+            "fn setup_routes(app: &mut Fluxor) {\n    let _unused = 1;\n    app.route(GET, \"/api\", api::index);\n}\n\nfn after() {\n}\n"
+        );
+
+        let source = "mod routes {\n    pub fn setup_routes(app: &mut Fluxor) {}\n}\n";
+        assert_eq!(
+            register_route(source, RouteKind::Api, "GET", "/api", "api::index").unwrap(),
+            "mod routes {\n    pub fn setup_routes(app: &mut Fluxor) {\n        app.route(GET, \"/api\", api::index);\n    }\n}\n"
+        );
+    }
+
+    #[test]
+    fn sources_without_setup_routes_are_rejected() {
+        assert_eq!(
+            register_route("fn main() {}\n", RouteKind::Api, "GET", "/api", "api::index"),
+            Err("no `setup_routes` function found".to_string())
+        );
+        assert!(register_route("fn setup_routes() {}\n", RouteKind::Api, "GET", "/api", "api::index").is_err());
+        assert!(register_route("fn setup_routes(app: &mut Fluxor) {\n", RouteKind::Api, "GET", "/api", "api::index").is_err());
+    }
+
+    #[test]
+    fn handlers_are_added_to_the_existing_import() {
+        assert_eq!(
+            import_handler("use api::hello_world;\n", "api", "users").as_deref(),
+            Some("use api::{hello_world, users};\n")
+        );
+        assert_eq!(
+            import_handler("use fluxor::prelude::*;\nuse api::{hello_world, msg};\n", "api", "users").as_deref(),
+            Some("use fluxor::prelude::*;\nuse api::{hello_world, msg, users};\n")
+        );
+        assert_eq!(
+            import_handler("use api::{\n    hello_world,\n    msg\n};\n", "api", "users").as_deref(),
+            Some("use api::{\n    hello_world,\n    msg,\n    users,\n};\n")
+        );
+        assert_eq!(import_handler("use pages::home;\n", "api", "users"), None);
+    }
+
+    #[test]
+    fn imported_handlers_are_not_imported_twice() {
+        for source in ["use api::users;\n", "use api::{hello_world, users};\n", "use api::*;\n"] {
+            assert_eq!(import_handler(source, "api", "users").as_deref(), Some(source));
+        }
+    }
+}
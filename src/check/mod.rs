@@ -0,0 +1,228 @@
+use std::fmt;
+use std::path::Path;
+
+use crate::error::{FluxorCliError, Result};
+use crate::routes::{Function, Route, RouteTable};
+
+/// Runs `fluxor check routes` on the project at `root`, printing one line per problem.
+pub fn check_project_routes(root: &Path) -> Result<()> {
+    let table = RouteTable::load(root)?;
+    let issues = check_routes(&table);
+
+    for issue in &issues {
+        println!("{}", issue);
+    }
+
+    if issues.is_empty() {
+        println!("No route problems found in {} routes.", table.routes.len());
+        Ok(())
+    } else {
+        Err(FluxorCliError::RouteProblems(issues.len()))
+    }
+}
+
+/// A problem found by `fluxor check routes`.
+#[derive(Debug, Clone)]
+pub enum RouteIssue {
+    /// The same method and path (up to parameter names) is registered twice; the second never matches.
+    Duplicate { route: Route, first: Route },
+    /// An earlier route with parameter segments matches every request of `route`.
+    Shadowed { route: Route, by: Route },
+    /// A handler function that no route registers.
+    UnusedHandler(Function),
+    /// A route whose handler is not defined in the project or any imported crate.
+    UndefinedHandler(Route),
+}
+
+impl fmt::Display for RouteIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RouteIssue::Duplicate { route, first } => write!(
+                f,
+                "{}: duplicate route {} {}, already registered at {}",
+                route.location, route.method, route.path, first.location
+            ),
+            RouteIssue::Shadowed { route, by } => write!(
+                f,
+                "{}: {} {} is unreachable, {} {} at {} matches its requests first",
+                route.location, route.method, route.path, by.method, by.path, by.location
+            ),
+            RouteIssue::UnusedHandler(function) => {
+                write!(f, "{}: handler `{}` is not registered in any route", function.location, function.path)
+            }
+            RouteIssue::UndefinedHandler(route) => write!(
+                f,
+                "{}: handler `{}` of {} {} is not defined",
+                route.location, route.handler, route.method, route.path
+            ),
+        }
+    }
+}
+
+/// Checks the routes of a project.
+///
+/// Fluxor tries routes in registration order and calls the first one whose method matches and
+/// whose `<param>` segments cover the request path, so a later route that an earlier one fully
+/// covers can never be reached.
+pub fn check_routes(table: &RouteTable) -> Vec<RouteIssue> {
+    let mut issues = Vec::new();
+
+    for (index, route) in table.routes.iter().enumerate() {
+        if let Some(issue) = unreachable(&table.routes, index) {
+            issues.push(issue);
+        }
+
+        if route.function.is_none() && !route.external {
+            issues.push(RouteIssue::UndefinedHandler(route.clone()));
+        }
+    }
+
+    for function in table.functions.iter().filter(|function| function.is_handler) {
+        let registered = table
+            .routes
+            .iter()
+            .any(|route| route.function.as_ref().is_some_and(|handler| handler.path == function.path));
+
+        if !registered {
+            issues.push(RouteIssue::UnusedHandler(function.clone()));
+        }
+    }
+
+    issues
+}
+
+/// The duplicate or shadowed issue of `routes[index]`, if an earlier route handles all its requests.
+pub(crate) fn unreachable(routes: &[Route], index: usize) -> Option<RouteIssue> {
+    let route = &routes[index];
+
+    routes[..index]
+        .iter()
+        .filter(|earlier| earlier.method == route.method)
+        .find_map(|earlier| match covers(&earlier.path, &route.path)? {
+            true => Some(RouteIssue::Duplicate {
+                route: route.clone(),
+                first: earlier.clone(),
+            }),
+            false => Some(RouteIssue::Shadowed {
+                route: route.clone(),
+                by: earlier.clone(),
+            }),
+        })
+}
+
+/// Whether every request matching `later` also matches `earlier`: `Some(true)` when both paths
+/// are the same up to parameter names, `Some(false)` when parameters of `earlier` cover literals.
+fn covers(earlier: &str, later: &str) -> Option<bool> {
+    let is_param = |segment: &str| segment.starts_with('<') && segment.ends_with('>');
+    let earlier: Vec<&str> = earlier.split('/').collect();
+    let later: Vec<&str> = later.split('/').collect();
+
+    if earlier.len() != later.len() {
+        return None;
+    }
+
+    let mut same = true;
+    for (earlier, later) in earlier.iter().zip(&later) {
+        match (is_param(earlier), is_param(later)) {
+            (true, true) => {}
+            (true, false) => same = false,
+            (false, true) => return None,
+            (false, false) if earlier != later => return None,
+            (false, false) => {}
+        }
+    }
+
+    Some(same)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    /// Checks a project whose `main` registers `routes` before calling `setup_routes`, which
+    /// registers `setup`; every route uses the `page` handler.
+    fn check(routes: &[(&str, &str)], setup: &[(&str, &str)]) -> Vec<String> {
+        let register = |routes: &[(&str, &str)]| {
+            routes
+                .iter()
+                .map(|(method, path)| format!("    app.route({}, \"{}\", crate::page);\n", method, path))
+                .collect::<String>()
+        };
+        let main = format!(
+            "mod routes;\n\nfn main() {{\n    let mut app = Fluxor::new();\n{}    routes::setup_routes(&mut app);\n}}\n\nfn page() {{}}\n",
+            register(routes)
+        );
+        let setup = format!("pub fn setup_routes(app: &mut Fluxor) {{\n{}}}\n", register(setup));
+        let sources = [(PathBuf::from("src/main.rs"), main), (PathBuf::from("src/routes/mod.rs"), setup)];
+
+        let table = RouteTable::from_sources(&sources, Some("app")).unwrap();
+        check_routes(&table)
+            .iter()
+            .map(|issue| match issue {
+                RouteIssue::Duplicate { route, first } => format!("duplicate {} {} of {}", route.method, route.path, first.path),
+                RouteIssue::Shadowed { route, by } => format!("shadowed {} {} by {}", route.method, route.path, by.path),
+                issue => issue.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parameters_shadow_later_literal_routes() {
+        assert_eq!(
+            check(&[("GET", "/api/<id>"), ("GET", "/api/req"), ("POST", "/api/msg")], &[]),
+            ["shadowed GET /api/req by /api/<id>"]
+        );
+        assert!(check(&[("GET", "/api/req"), ("GET", "/api/<id>")], &[]).is_empty());
+        assert!(check(&[("GET", "/api/<id>"), ("GET", "/api/<id>/edit")], &[]).is_empty());
+    }
+
+    #[test]
+    fn catch_all_parameters_shadow_every_route_of_their_length() {
+        // Fluxor splits `/` into two empty segments, so `/<page>` also matches it
+        assert_eq!(
+            check(&[("GET", "/<page>"), ("GET", "/about"), ("GET", "/"), ("GET", "/pricing/plans")], &[]),
+            ["shadowed GET /about by /<page>", "shadowed GET / by /<page>"]
+        );
+        assert_eq!(
+            check(&[("GET", "/<section>/<page>"), ("GET", "/docs/<page>"), ("GET", "/docs/intro")], &[]),
+            ["shadowed GET /docs/<page> by /<section>/<page>", "shadowed GET /docs/intro by /<section>/<page>"]
+        );
+    }
+
+    #[test]
+    fn duplicates_are_reported_up_to_parameter_names() {
+        assert_eq!(
+            check(&[("GET", "/users/<id>"), ("GET", "/users/<name>"), ("DELETE", "/users/<id>")], &[]),
+            ["duplicate GET /users/<name> of /users/<id>"]
+        );
+        assert_eq!(check(&[("GET", "/"), ("GET", "/")], &[]), ["duplicate GET / of /"]);
+    }
+
+    #[test]
+    fn routes_are_checked_in_registration_order() {
+        // `main` registers its route after `setup_routes` although main.rs sorts first
+        let main = "mod routes;\n\nfn main() {\n    let mut app = Fluxor::new();\n    routes::setup_routes(&mut app);\n    app.route(GET, \"/api/req\", page);\n}\n\nfn page() {}\n";
+        let setup = "pub fn setup_routes(app: &mut Fluxor) {\n    app.route(GET, \"/api/<id>\", crate::page);\n}\n";
+        let sources = [(PathBuf::from("src/main.rs"), main.to_string()), (PathBuf::from("src/routes/mod.rs"), setup.to_string())];
+
+        let issues = check_routes(&RouteTable::from_sources(&sources, Some("app")).unwrap());
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(&issues[0], RouteIssue::Shadowed { route, by } if route.path == "/api/req" && by.path == "/api/<id>"));
+
+        assert_eq!(check(&[("GET", "/api/req")], &[("GET", "/api/<id>")]), Vec::<String>::new());
+        assert_eq!(check(&[("GET", "/api/<id>")], &[("GET", "/api/req")]), ["shadowed GET /api/req by /api/<id>"]);
+    }
+
+    #[test]
+    fn undefined_and_unused_handlers_are_reported() {
+        let main = "use fluxor::prelude::*;\n\nfn main() {\n    let mut app = Fluxor::new();\n    app.route(GET, \"/\", missing);\n    app.route(GET, \"/client\", serve_http_client);\n}\n\nfn unused(_req: Req, _params: Params) -> Reply {\n    todo!()\n}\n";
+        let sources = [(PathBuf::from("src/main.rs"), main.to_string())];
+
+        let issues = check_routes(&RouteTable::from_sources(&sources, Some("app")).unwrap());
+        assert_eq!(issues.len(), 2, "{:?}", issues);
+        assert!(matches!(&issues[0], RouteIssue::UndefinedHandler(route) if route.handler == "missing"));
+        assert!(matches!(&issues[1], RouteIssue::UnusedHandler(function) if function.path == "crate::unused"));
+    }
+}
@@ -0,0 +1,185 @@
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command as ProcessCommand, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use cargo_metadata::Message;
+use notify::{EventKind, RecursiveMode, Watcher};
+
+use crate::error::{FluxorCliError, Result};
+use crate::server::ServerConfig;
+
+/// How long to keep collecting file events after the first one, so a save touching several
+/// files triggers a single rebuild.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// What a batch of file changes requires, from least to most work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Change {
+    /// Only files in a static directory changed; Fluxor serves them from disk on every request.
+    Assets,
+    /// The `.env` file changed; the server reads it on start.
+    Restart,
+    /// Rust sources, other files under `src` (e.g. `include_str!` templates) or Cargo.toml changed.
+    Rebuild,
+}
+
+/// Options of `fluxor dev`.
+///
+/// Builds and runs the project, then watches `src/`, the static directories passed to
+/// `app.set_dir(...)`, Cargo.toml and `.env`, rebuilding and restarting the server as needed.
+#[derive(Debug, Clone, Default)]
+pub struct DevServer<'a> {
+    /// The binary to run when the package has several.
+    pub bin: Option<&'a str>,
+}
+
+impl DevServer<'_> {
+    /// Runs the development loop for the project at `root` until the process is interrupted.
+    pub fn run(&self, root: &Path) -> Result<()> {
+        let watch_error = |source: notify::Error| FluxorCliError::io(root, io::Error::other(source));
+
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender).map_err(watch_error)?;
+
+        let mut config = ServerConfig::load(root);
+        watcher.watch(&root.join("src"), RecursiveMode::Recursive).map_err(watch_error)?;
+        watcher.watch(root, RecursiveMode::NonRecursive).map_err(watch_error)?;
+        for dir in &config.static_dirs {
+            let dir = root.join(dir);
+            if dir.is_dir() && !dir.starts_with(root.join("src")) {
+                watcher.watch(&dir, RecursiveMode::Recursive).map_err(watch_error)?;
+            }
+        }
+
+        let mut executable = self.build(root)?;
+        let mut server = executable.as_deref().map(|executable| start(root, executable, &config)).transpose()?;
+
+        while let Ok(event) = events.recv() {
+            let mut changed: Vec<PathBuf> = Vec::new();
+            let mut collect = |event: notify::Result<notify::Event>| {
+                if let Ok(event) = event
+                    && !matches!(event.kind, EventKind::Access(_))
+                {
+                    changed.extend(event.paths);
+                }
+            };
+            collect(event);
+            while let Ok(event) = events.recv_timeout(DEBOUNCE) {
+                collect(event);
+            }
+
+            let Some(change) = changed.iter().filter_map(|path| classify(root, path, &config)).max() else {
+                continue;
+            };
+
+            match change {
+                Change::Assets => println!("Assets changed; they are served from disk, no rebuild needed."),
+                Change::Restart | Change::Rebuild => {
+                    stop(&mut server);
+                    config = ServerConfig::load(root);
+
+                    if change == Change::Rebuild || executable.is_none() {
+                        println!("Sources changed; rebuilding...");
+                        executable = self.build(root)?;
+                    } else {
+                        println!(".env changed; restarting...");
+                    }
+                    server = executable.as_deref().map(|executable| start(root, executable, &config)).transpose()?;
+                }
+            }
+        }
+
+        stop(&mut server);
+        Ok(())
+    }
+
+    /// Builds the project and returns the executable to run, or `None` if the build failed.
+    fn build(&self, root: &Path) -> Result<Option<PathBuf>> {
+        let mut command = ProcessCommand::new("cargo");
+        command
+            .current_dir(root)
+            .args(["build", "--message-format=json-render-diagnostics"])
+            .stdout(Stdio::piped());
+        if let Some(bin) = self.bin {
+            command.args(["--bin", bin]);
+        }
+
+        let mut cargo = command.spawn().map_err(|source| FluxorCliError::io(root, source))?;
+        let mut executables = Vec::new();
+        if let Some(stdout) = cargo.stdout.take() {
+            for message in Message::parse_stream(BufReader::new(stdout)).flatten() {
+                if let Message::CompilerArtifact(artifact) = message
+                    && let Some(executable) = artifact.executable
+                {
+                    executables.push((artifact.target.name, PathBuf::from(executable)));
+                }
+            }
+        }
+        let status = cargo.wait().map_err(|source| FluxorCliError::io(root, source))?;
+
+        if !status.success() {
+            println!("Build failed; waiting for changes...");
+            return Ok(None);
+        }
+
+        match executables.as_slice() {
+            [] => Err(FluxorCliError::ProjectLayout {
+                path: root.to_path_buf(),
+                message: "the package has no binary to run".to_string(),
+            }),
+            [(_, executable)] => Ok(Some(executable.clone())),
+            _ => Err(FluxorCliError::ProjectLayout {
+                path: root.to_path_buf(),
+                message: format!(
+                    "the package has several binaries ({}); pick one with --bin",
+                    executables.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>().join(", ")
+                ),
+            }),
+        }
+    }
+}
+
+/// What a change to `path` requires, or `None` if it does not affect the server.
+fn classify(root: &Path, path: &Path, config: &ServerConfig) -> Option<Change> {
+    let name = path.file_name()?.to_string_lossy();
+    // Editor swap and backup files
+    if name.starts_with('.') && name != ".env" || name.ends_with('~') || name.ends_with(".swp") || name.ends_with(".swx") {
+        return None;
+    }
+
+    if config.static_dirs.iter().any(|dir| path.starts_with(root.join(dir))) {
+        return Some(Change::Assets);
+    }
+    if path.starts_with(root.join("src")) {
+        return Some(Change::Rebuild);
+    }
+
+    match path.strip_prefix(root).ok()?.to_str()? {
+        "Cargo.toml" => Some(Change::Rebuild),
+        ".env" => Some(Change::Restart),
+        _ => None,
+    }
+}
+
+fn start(root: &Path, executable: &Path, config: &ServerConfig) -> Result<Child> {
+    let child = ProcessCommand::new(executable)
+        .current_dir(root)
+        .spawn()
+        .map_err(|source| FluxorCliError::io(executable, source))?;
+
+    match config.url() {
+        Some(url) => println!("Serving at {} (watching for changes, Ctrl-C to stop)", url),
+        None => println!("Server started (watching for changes, Ctrl-C to stop)"),
+    }
+
+    Ok(child)
+}
+
+fn stop(server: &mut Option<Child>) {
+    if let Some(mut child) = server.take() {
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
@@ -0,0 +1,268 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::process::Command as ProcessCommand;
+
+use semver::{Version, VersionReq};
+use toml_edit::DocumentMut;
+
+use crate::error::{FluxorCliError, Result};
+use crate::server::{ServerConfig, read_dotenv};
+use crate::version::{VersionResolver, VersionSource, fetch_latest_version};
+
+/// The first Rust release supporting edition 2024, which every example uses.
+pub const MIN_RUST_VERSION: Version = Version::new(1, 85, 0);
+
+/// The outcome of a single `fluxor doctor` check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Status {
+    Ok,
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Status::Ok => "ok",
+            Status::Warning => "warn",
+            Status::Error => "error",
+        })
+    }
+}
+
+/// A check reported by `fluxor doctor`, grouped under a section such as `Toolchain`.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub section: String,
+    pub status: Status,
+    pub message: String,
+}
+
+/// Runs `fluxor doctor` from `directory`, printing every check; errors make the command fail.
+pub fn run_doctor(directory: &Path) -> Result<()> {
+    let diagnostics = diagnose(directory);
+
+    let mut section = None;
+    for diagnostic in &diagnostics {
+        if section != Some(&diagnostic.section) {
+            if section.is_some() {
+                println!();
+            }
+            println!("{}", diagnostic.section);
+            section = Some(&diagnostic.section);
+        }
+        println!("  {:<6} {}", diagnostic.status, diagnostic.message);
+    }
+
+    match diagnostics.iter().filter(|diagnostic| diagnostic.status == Status::Error).count() {
+        0 => Ok(()),
+        errors => Err(FluxorCliError::DoctorProblems(errors)),
+    }
+}
+
+/// Checks the toolchain, the crates.io index and, inside a Fluxor project, the project itself.
+pub fn diagnose(directory: &Path) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    toolchain(&mut diagnostics);
+    let latest = crates_index(&mut diagnostics);
+
+    match directory.ancestors().find(|dir| dir.join("Cargo.toml").is_file()) {
+        Some(root) => project(&mut diagnostics, root, latest.as_ref()),
+        None => push(&mut diagnostics, "Project", Status::Ok, "not inside a Cargo package; project checks skipped"),
+    }
+
+    diagnostics
+}
+
+fn push(diagnostics: &mut Vec<Diagnostic>, section: &str, status: Status, message: impl Into<String>) {
+    diagnostics.push(Diagnostic {
+        section: section.to_string(),
+        status,
+        message: message.into(),
+    });
+}
+
+/// The first line printed by `program --version`.
+fn tool_version(program: &str) -> Option<String> {
+    let output = ProcessCommand::new(program).arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout).lines().next().map(str::to_string)
+}
+
+fn toolchain(diagnostics: &mut Vec<Diagnostic>) {
+    const SECTION: &str = "Toolchain";
+
+    match tool_version("cargo") {
+        Some(version) => push(diagnostics, SECTION, Status::Ok, version),
+        None => push(diagnostics, SECTION, Status::Error, "`cargo` was not found; install Rust from https://rustup.rs"),
+    }
+
+    let Some(rustc) = tool_version("rustc") else {
+        push(diagnostics, SECTION, Status::Error, "`rustc` was not found; install Rust from https://rustup.rs");
+        return;
+    };
+
+    // `rustc 1.85.0 (4d91de4e4 2025-02-17)`
+    match rustc.split_whitespace().nth(1).and_then(|version| Version::parse(version).ok()) {
+        Some(version) if version >= MIN_RUST_VERSION => {
+            push(diagnostics, SECTION, Status::Ok, format!("{} supports edition 2024", rustc))
+        }
+        Some(_) => push(
+            diagnostics,
+            SECTION,
+            Status::Error,
+            format!("{} does not support edition 2024 (requires {} or later); run `rustup update`", rustc, MIN_RUST_VERSION),
+        ),
+        None => push(diagnostics, SECTION, Status::Warning, format!("{}: unknown version format", rustc)),
+    }
+}
+
+/// Checks that the latest fluxor version can be resolved, returning it.
+fn crates_index(diagnostics: &mut Vec<Diagnostic>) -> Option<Version> {
+    const SECTION: &str = "Crates index";
+
+    let cached = VersionResolver::new(true).latest("fluxor").ok();
+
+    let latest = match fetch_latest_version("fluxor") {
+        Ok(version) => {
+            push(diagnostics, SECTION, Status::Ok, format!("crates.io is reachable; the latest fluxor is {}", version));
+            Some(version)
+        }
+        Err(error) => {
+            let status = if cached.is_some() { Status::Warning } else { Status::Error };
+            // Cargo's error is followed by the HTTP response details
+            let error = error.to_string();
+            push(diagnostics, SECTION, status, format!("crates.io is not reachable: {}", error.lines().next().unwrap_or_default()));
+            None
+        }
+    };
+
+    match &cached {
+        Some((version, source)) => {
+            let cache = match source {
+                VersionSource::CrateCache => "downloaded crates",
+                _ => "registry index",
+            };
+            push(diagnostics, SECTION, Status::Ok, format!("fluxor {} is in the local {} cache; `--offline` works", version, cache))
+        }
+        None => push(
+            diagnostics,
+            SECTION,
+            Status::Warning,
+            "fluxor is not in the local registry cache; `fluxor new --offline` needs an explicit --version",
+        ),
+    }
+
+    latest.or_else(|| cached.map(|(version, _)| version)).and_then(|version| Version::parse(&version).ok())
+}
+
+fn project(diagnostics: &mut Vec<Diagnostic>, root: &Path, latest: Option<&Version>) {
+    let section = format!("Project {}", root.display());
+    let section = section.as_str();
+
+    let manifest = fs::read_to_string(root.join("Cargo.toml"))
+        .ok()
+        .and_then(|manifest| manifest.parse::<DocumentMut>().ok());
+    let requirement = manifest
+        .as_ref()
+        .and_then(|manifest| manifest.get("dependencies")?.get("fluxor").cloned())
+        .and_then(|dependency| match dependency.as_str() {
+            Some(version) => Some(version.to_string()),
+            None => dependency.get("version")?.as_str().map(str::to_string),
+        });
+
+    let Some(requirement) = requirement else {
+        push(diagnostics, section, Status::Ok, "the package does not depend on fluxor; project checks skipped");
+        return;
+    };
+
+    fluxor_version(diagnostics, section, root, &requirement, latest);
+
+    let config = ServerConfig::load(root);
+
+    let env = read_dotenv(root);
+    let mut keys: Vec<&str> = Vec::new();
+    for env_var in &config.env_vars {
+        if keys.contains(&env_var.key.as_str()) {
+            continue;
+        }
+        keys.push(&env_var.key);
+
+        let (status, message) = if env.contains_key(&env_var.key) {
+            (Status::Ok, format!("{} is set in .env", env_var.key))
+        } else if std::env::var_os(&env_var.key).is_some() {
+            (Status::Ok, format!("{} is set in the environment", env_var.key))
+        } else if let Some(default) = &env_var.default {
+            (Status::Warning, format!("{} ({}) is not in .env; the default \"{}\" is used", env_var.key, env_var.location, default))
+        } else {
+            (Status::Error, format!("{} ({}) is not in .env or the environment", env_var.key, env_var.location))
+        };
+        push(diagnostics, section, status, message);
+    }
+
+    for dir in &config.static_dirs {
+        if root.join(dir).is_dir() {
+            push(diagnostics, section, Status::Ok, format!("static directory '{}' exists", dir.display()));
+        } else {
+            push(
+                diagnostics,
+                section,
+                Status::Error,
+                format!("static directory '{}' passed to `set_dir` does not exist", dir.display()),
+            );
+        }
+    }
+}
+
+fn fluxor_version(diagnostics: &mut Vec<Diagnostic>, section: &str, root: &Path, requirement: &str, latest: Option<&Version>) {
+    let Ok(parsed) = VersionReq::parse(requirement) else {
+        push(diagnostics, section, Status::Error, format!("fluxor = \"{}\" is not a valid version requirement", requirement));
+        return;
+    };
+
+    let locked = locked_version(root, "fluxor");
+    let current = match &locked {
+        Some(locked) => format!("fluxor {} (locked {})", requirement, locked),
+        None => format!("fluxor {}", requirement),
+    };
+
+    let Some(latest) = latest else {
+        push(diagnostics, section, Status::Warning, format!("{}; the latest version is unknown", current));
+        return;
+    };
+
+    if !parsed.matches(latest) {
+        push(
+            diagnostics,
+            section,
+            Status::Warning,
+            format!("{} is outdated: {} is available; update the requirement in Cargo.toml", current, latest),
+        );
+    } else if locked.as_ref().is_some_and(|locked| locked < latest) {
+        push(
+            diagnostics,
+            section,
+            Status::Warning,
+            format!("{} is outdated: {} is available; run `cargo update -p fluxor`", current, latest),
+        );
+    } else {
+        push(diagnostics, section, Status::Ok, format!("{} is up to date", current));
+    }
+}
+
+/// The version of `crate_name` in the Cargo.lock of the package at `root` or its workspace.
+fn locked_version(root: &Path, crate_name: &str) -> Option<Version> {
+    let lock = root.ancestors().find_map(|dir| fs::read_to_string(dir.join("Cargo.lock")).ok())?;
+    let lock = lock.parse::<DocumentMut>().ok()?;
+
+    lock.get("package")?
+        .as_array_of_tables()?
+        .iter()
+        .filter(|package| package.get("name").and_then(|name| name.as_str()) == Some(crate_name))
+        .filter_map(|package| Version::parse(package.get("version")?.as_str()?).ok())
+        .max()
+}
@@ -0,0 +1,164 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// Errors returned by the Fluxor CLI library.
+///
+/// Each variant maps to a distinct process exit code through [`FluxorCliError::exit_code`],
+/// so scripts embedding the `fluxor` binary can tell the failure modes apart.
+#[derive(Debug)]
+pub enum FluxorCliError {
+    /// The project directory already exists.
+    DirectoryExists(PathBuf),
+    /// The requested example is not in the registry.
+    UnknownExample(String),
+    /// The version of a dependency crate could not be resolved.
+    VersionLookup { crate_name: String, message: String },
+    /// A filesystem operation failed on `path`.
+    Io { path: PathBuf, source: io::Error },
+    /// A project, page or component name cannot be turned into a valid crate name or Rust identifier.
+    InvalidName(String),
+    /// A requested version is neither `latest` nor a valid semver requirement.
+    InvalidVersion { crate_name: String, version: String },
+    /// A `--dep` override names a crate the example does not depend on.
+    UnknownDependency { example: String, crate_name: String, dependencies: Vec<String> },
+    /// An existing Cargo.toml cannot be read or updated.
+    Manifest { path: PathBuf, message: String },
+    /// Generating the project would overwrite these existing files.
+    WouldOverwrite(Vec<PathBuf>),
+    /// The project does not follow the layout a `fluxor add` generator expects.
+    ProjectLayout { path: PathBuf, message: String },
+    /// The route passed to `fluxor add route` is invalid or already exists.
+    InvalidRoute(String),
+    /// A Rust source file of the project cannot be parsed.
+    SourceParse { path: PathBuf, message: String },
+    /// `fluxor check routes` found this many problems.
+    RouteProblems(usize),
+    /// `fluxor doctor` found this many errors.
+    DoctorProblems(usize),
+    /// A `--template` directory or its `fluxor-template.toml` manifest is invalid.
+    InvalidTemplate { path: PathBuf, message: String },
+    /// A git template could not be cloned, fetched or checked out.
+    TemplateFetch { url: String, message: String },
+    /// A value given for a template variable is unknown, missing or invalid.
+    TemplateValue { variable: String, message: String },
+    /// A post-generation hook failed; the project itself was created.
+    HookFailed { hook: String, message: String },
+}
+
+/// A specialized `Result` type for Fluxor CLI operations.
+pub type Result<T> = std::result::Result<T, FluxorCliError>;
+
+impl FluxorCliError {
+    /// Builds an [`FluxorCliError::Io`] error for the given path.
+    pub fn io(path: impl Into<PathBuf>, source: io::Error) -> Self {
+        FluxorCliError::Io { path: path.into(), source }
+    }
+
+    /// The process exit code used by the `fluxor` binary for this error.
+    ///
+    /// Exit codes start at 3 to stay clear of clap's usage error code (2).
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            FluxorCliError::InvalidName(_) => 3,
+            FluxorCliError::UnknownExample(_) => 4,
+            FluxorCliError::DirectoryExists(_) => 5,
+            FluxorCliError::VersionLookup { .. } => 6,
+            FluxorCliError::Io { .. } => 7,
+            FluxorCliError::InvalidVersion { .. } => 8,
+            FluxorCliError::UnknownDependency { .. } => 9,
+            FluxorCliError::Manifest { .. } => 10,
+            FluxorCliError::WouldOverwrite(_) => 11,
+            FluxorCliError::ProjectLayout { .. } => 12,
+            FluxorCliError::InvalidRoute(_) => 13,
+            FluxorCliError::SourceParse { .. } => 14,
+            FluxorCliError::RouteProblems(_) => 15,
+            FluxorCliError::DoctorProblems(_) => 16,
+            FluxorCliError::InvalidTemplate { .. } => 17,
+            FluxorCliError::TemplateFetch { .. } => 18,
+            FluxorCliError::TemplateValue { .. } => 19,
+            FluxorCliError::HookFailed { .. } => 20,
+        }
+    }
+}
+
+impl fmt::Display for FluxorCliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FluxorCliError::DirectoryExists(path) => {
+                write!(f, "Project directory '{}' already exists.", path.display())
+            }
+            FluxorCliError::UnknownExample(example) => {
+                write!(f, "Unknown example specified: {}. Run `fluxor list` to see all examples.", example)
+            }
+            FluxorCliError::VersionLookup { crate_name, message } => {
+                write!(f, "Failed to fetch the version of {}: {}", crate_name, message)
+            }
+            FluxorCliError::Io { path, source } => {
+                write!(f, "I/O error on '{}': {}", path.display(), source)
+            }
+            FluxorCliError::InvalidName(name) => {
+                write!(f, "'{}' is not a valid name; names must convert to a Rust identifier that is not a keyword.", name)
+            }
+            FluxorCliError::InvalidVersion { crate_name, version } => {
+                write!(
+                    f,
+                    "'{}' is not a valid version of {}: expected `latest`, a version such as `1.1.2` or a requirement such as `^1.1` or `>=1, <2`.",
+                    version, crate_name
+                )
+            }
+            FluxorCliError::UnknownDependency { example, crate_name, dependencies } => {
+                write!(
+                    f,
+                    "The '{}' example does not depend on {}; its dependencies are: {}.",
+                    example,
+                    crate_name,
+                    dependencies.join(", ")
+                )
+            }
+            FluxorCliError::Manifest { path, message } => {
+                write!(f, "Invalid manifest '{}': {}", path.display(), message)
+            }
+            FluxorCliError::WouldOverwrite(paths) => {
+                let paths: Vec<String> = paths.iter().map(|path| path.display().to_string()).collect();
+                write!(f, "Refusing to overwrite existing files: {}. Pass --force to overwrite them.", paths.join(", "))
+            }
+            FluxorCliError::ProjectLayout { path, message } => {
+                write!(f, "Unexpected project layout at '{}': {}. Nothing was changed.", path.display(), message)
+            }
+            FluxorCliError::InvalidRoute(message) => {
+                write!(f, "Invalid route: {}.", message)
+            }
+            FluxorCliError::SourceParse { path, message } => {
+                write!(f, "Failed to parse '{}': {}", path.display(), message)
+            }
+            FluxorCliError::RouteProblems(count) => {
+                write!(f, "{} route problem{} found.", count, if *count == 1 { "" } else { "s" })
+            }
+            FluxorCliError::DoctorProblems(count) => {
+                write!(f, "fluxor doctor found {} error{}.", count, if *count == 1 { "" } else { "s" })
+            }
+            FluxorCliError::InvalidTemplate { path, message } => {
+                write!(f, "Invalid template '{}': {}", path.display(), message)
+            }
+            FluxorCliError::TemplateFetch { url, message } => {
+                write!(f, "Failed to fetch the template '{}': {}", url, message)
+            }
+            FluxorCliError::TemplateValue { variable, message } => {
+                write!(f, "Invalid value for the template variable '{}': {}", variable, message)
+            }
+            FluxorCliError::HookFailed { hook, message } => {
+                write!(f, "The project was created, but the hook `{}` failed: {}", hook, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for FluxorCliError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FluxorCliError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -1,18 +1,85 @@
-use std::fs;
-use std::path::Path;
-
-use crate::metadata::*;
+use crate::examples::{DependencyVersions, Example, dependency_version};
 use crate::utils::to_crate_name;
 
-// metadata files
+// examples
+
+/// The `helloworld` example.
+pub struct HelloWorld;
+
+impl Example for HelloWorld {
+    fn name(&self) -> &'static str {
+        "helloworld"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["hello-world"]
+    }
+
+    fn category(&self) -> &'static str {
+        "Hello World"
+    }
+
+    fn description(&self) -> &'static str {
+        "Minimal server that responds with an HTML greeting."
+    }
+
+    fn cargo_toml(&self, package_name: &str, versions: &DependencyVersions) -> String {
+        hello_world_cargo_toml(package_name, dependency_version(versions, "fluxor"))
+    }
+}
+
+/// The `helloworld-api` example.
+pub struct HelloWorldApi;
+
+impl Example for HelloWorldApi {
+    fn name(&self) -> &'static str {
+        "helloworld-api"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["hello-world-api"]
+    }
+
+    fn category(&self) -> &'static str {
+        "Hello World"
+    }
+
+    fn description(&self) -> &'static str {
+        "JSON greeting endpoint plus the built-in HTTP client."
+    }
+
+    fn cargo_toml(&self, package_name: &str, versions: &DependencyVersions) -> String {
+        hello_world_cargo_toml(package_name, dependency_version(versions, "fluxor"))
+    }
+}
+
+/// The `helloworld-api-server` example.
+pub struct HelloWorldApiServer;
+
+impl Example for HelloWorldApiServer {
+    fn name(&self) -> &'static str {
+        "helloworld-api-server"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["hello-world-api-server"]
+    }
+
+    fn category(&self) -> &'static str {
+        "Hello World"
+    }
 
-pub fn config_metadata(path: &Path) {
-  // .gitignore
-  let gitignore_content = r#"/target
-    "#;
-  create_gitignore(path, gitignore_content);
+    fn description(&self) -> &'static str {
+        "JSON greeting API built as a `server` binary from src/server.rs."
+    }
+
+    fn cargo_toml(&self, package_name: &str, versions: &DependencyVersions) -> String {
+        hello_world_api_server_cargo_toml(package_name, dependency_version(versions, "fluxor"))
+    }
 }
 
+// Cargo.toml
+
 pub fn hello_world_cargo_toml(name: &str, fluxor_version:  &str) -> String {
     let package_name = to_crate_name(name);
     format!(
@@ -28,63 +95,6 @@ fluxor = "{}"
         )
 }
 
-// src/main.rs
-
-pub fn hello_world_main_rs(path:  &Path) {
-    fs::write(
-        path.join("main.rs"),
-        r#"use fluxor::prelude::*;
-
-fn hello(_req: Req, _params: Params) -> Reply {
-    boxed(async {
-        Ok(Response::builder()
-            .header("Content-Type", "text/html; charset=UTF-8")
-            .body(Body::from("<h1>👋 Hello, World!</h1>"))
-            .unwrap())
-    })
-}
-
-#[tokio::main]
-async fn main() {
-    let mut app = Fluxor::new();        // Initialize the application.
-    app.route(GET, "/", hello);         // Set the route (method, path, handler).
-    app.run("127.0.0.1", "8080").await; // Start the HTTP server (host, port).
-}
-"#,
-    )
-    .expect("Failed to create main.rs for helloworld");
-}
-
-// src/main.rs
-
-pub fn hello_world_api_main_rs(path:  &Path) {
-    fs::write(
-        path.join("main.rs"),
-        r##"use fluxor::prelude::*;
-
-fn hello(_req: Req, _params: Params) -> Reply {
-    boxed(async move {
-       let json_response = format!(r#"{{"message": "👋 Hello, World!"}}"#);
-        
-        Ok(Response::builder()
-            .header("Content-Type", "application/json")
-            .body(Body::from(json_response))
-            .unwrap())
-    })
-}
-
-#[tokio::main]
-async fn main() {
-    let mut app = Fluxor::new();                        // Initialize the application.
-    app.route(GET, "/", hello);                         // Set the route (method, path, handler).
-    app.route(GET, "/http-client", serve_http_client);  // A simple http client to test your application.
-    app.run("127.0.0.1", "8080").await;                 // Start the HTTP server (host, port).
-}
-"##,
-    )
-    .expect("Failed to create main.rs for fluxor-project");
-}
-
 pub fn hello_world_api_server_cargo_toml(name: &str, fluxor_version:  &str) -> String {
     let package_name = to_crate_name(name);
     format!(
@@ -103,38 +113,3 @@ path = "src/server.rs"
             package_name, fluxor_version // Use `name` here to set the package name
         )
 }
-
-// src/server.rs
-
-pub fn hello_world_api_server_rs(path:  &Path) {
-    fs::write(
-        path.join("server.rs"),
-        r##"use fluxor::prelude::*;
-
-fn hello(_req: Req, _params: Params) -> Reply {
-    boxed(async move {
-       let json_response = do_json!(
-        r#"
-            {"message": "{{waving_hand_emoji}} Hello, World!"}
-        "#, 
-        waving_hand_emoji = "👋"
-    );
-        
-        Ok(Response::builder()
-            .header("Content-Type", "application/json")
-            .body(Body::from(json_response))
-            .unwrap())
-    })
-}
-
-#[tokio::main]
-async fn main() {
-    let mut server = Fluxor::new();                         // Initialize the application.
-    server.route(POST, "/", hello);                         // Set the route (method, path, handler).
-    server.route(GET, "/http-client", serve_http_client);   // A simple http client to test your application.
-    server.run("127.0.0.1", "8080").await;                  // Start the HTTP server (host, port).
-}
-"##,
-    )
-    .expect("Failed to create server.rs for fluxor-project");
-}
\ No newline at end of file
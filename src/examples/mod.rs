@@ -5,3 +5,211 @@ pub mod dotenv;
 pub mod cans;
 pub mod db;
 pub mod fluxor;
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use include_dir::{Dir, DirEntry, include_dir};
+
+use crate::error::Result;
+use crate::hooks::Hook;
+use crate::sink::ProjectSink;
+use crate::template::render_placeholders;
+
+/// Resolved versions of an example's dependencies, keyed by crate name.
+pub type DependencyVersions = BTreeMap<String, String>;
+
+/// An example that can be passed to `fluxor new --example <name>`.
+///
+/// Every module under `src/examples` implements this trait for each of its examples,
+/// and the examples are registered in [`EXAMPLES`].
+pub trait Example: Sync {
+    /// The name accepted by the `--example` flag.
+    fn name(&self) -> &'static str;
+
+    /// Alternative names accepted by the `--example` flag.
+    fn aliases(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// The group the example is listed under (e.g. `Hello World`, `Routes`).
+    fn category(&self) -> &'static str;
+
+    /// A one-line summary of what the example demonstrates.
+    fn description(&self) -> &'static str;
+
+    /// The crates listed under `[dependencies]` in the generated Cargo.toml.
+    fn dependencies(&self) -> &'static [&'static str] {
+        &["fluxor"]
+    }
+
+    /// The version of a dependency other than `fluxor` used when `--dep` does not name one.
+    fn default_version(&self, _crate_name: &str) -> &'static str {
+        "latest"
+    }
+
+    /// The hooks run after the project is generated; `git init` unless overridden.
+    fn hooks(&self) -> Vec<Hook> {
+        vec![Hook::GitInit]
+    }
+
+    /// The files generated by the example, relative to the project directory and sorted: the
+    /// generated Cargo.toml and README.md plus the files embedded from `templates/<name>`.
+    fn files(&self) -> Vec<String> {
+        let mut files = vec!["Cargo.toml".to_string(), "README.md".to_string()];
+        if let Some(dir) = TEMPLATES.get_dir(self.name()) {
+            embedded_files(dir, Path::new(self.name()), &mut files);
+        }
+        files.sort();
+        files
+    }
+
+    /// Builds the Cargo.toml of the generated project.
+    fn cargo_toml(&self, package_name: &str, versions: &DependencyVersions) -> String;
+
+    /// Writes the example's source tree, embedded from `templates/<name>`, into `project_path` of `sink`.
+    fn generate(&self, sink: &mut dyn ProjectSink, project_path: &Path, package_name: &str) -> Result<()> {
+        let Some(dir) = TEMPLATES.get_dir(self.name()) else {
+            return Ok(());
+        };
+
+        let placeholders = [("cratename".to_string(), package_name.to_string())];
+        write_embedded(sink, dir, Path::new(self.name()), project_path, &placeholders)
+    }
+}
+
+/// The source trees of the examples, one directory per example under `templates/`.
+///
+/// Each tree is a crate checked by `tests/examples.rs`, apart from its Cargo.toml and README.md,
+/// which are generated. Dotfiles ignored by git, such as `.gitignore`, are stored without the dot.
+static TEMPLATES: Dir<'static> = include_dir!("$CARGO_MANIFEST_DIR/templates");
+
+/// Writes the files of the embedded `dir` into `project_path`, in order of their paths.
+fn write_embedded(
+    sink: &mut dyn ProjectSink,
+    dir: &Dir<'static>,
+    root: &Path,
+    project_path: &Path,
+    placeholders: &[(String, String)],
+) -> Result<()> {
+    let mut entries: Vec<&DirEntry<'static>> = dir.entries().iter().collect();
+    entries.sort_by_key(|entry| entry.path());
+
+    for entry in entries {
+        let path = project_path.join(embedded_path(entry, root));
+
+        match entry {
+            DirEntry::Dir(dir) => {
+                sink.create_dir(&path)?;
+                write_embedded(sink, dir, root, project_path, placeholders)?;
+            }
+            DirEntry::File(file) => match file.contents_utf8() {
+                Some(text) => sink.write_file(&path, render_placeholders(text, placeholders).as_bytes())?,
+                // Images and other binary files are copied as they are
+                None => sink.write_file(&path, file.contents())?,
+            },
+        }
+    }
+
+    Ok(())
+}
+
+/// Collects the paths `write_embedded` writes for the files of `dir`.
+fn embedded_files(dir: &Dir<'static>, root: &Path, files: &mut Vec<String>) {
+    for entry in dir.entries() {
+        match entry {
+            DirEntry::Dir(dir) => embedded_files(dir, root, files),
+            DirEntry::File(_) => files.push(embedded_path(entry, root).to_string_lossy().replace('\\', "/")),
+        }
+    }
+}
+
+/// The path of an embedded entry in the generated project, relative to the project directory.
+fn embedded_path(entry: &DirEntry<'static>, root: &Path) -> PathBuf {
+    let relative = entry.path().strip_prefix(root).unwrap_or(entry.path());
+    match relative.to_str() {
+        Some("gitignore") => PathBuf::from(".gitignore"),
+        _ => relative.to_path_buf(),
+    }
+}
+
+/// Returns the resolved version of `crate_name`, or an empty string if it was not resolved.
+pub fn dependency_version<'a>(versions: &'a DependencyVersions, crate_name: &str) -> &'a str {
+    versions.get(crate_name).map(String::as_str).unwrap_or_default()
+}
+
+/// Example categories in the order they are listed.
+pub const CATEGORIES: &[&str] = &["Hello World", "Routes", "Assets", "DotEnv", "Cans", "DB", "Fluxor"];
+
+/// Registry of every example supported by `fluxor new`.
+pub static EXAMPLES: &[&dyn Example] = &[
+    // Hello World Examples
+    &hello_world::HelloWorld,
+    &hello_world::HelloWorldApi,
+    &hello_world::HelloWorldApiServer,
+    // Routes Examples
+    &routes::Routes,
+    &routes::RoutesProject,
+    // Assets Examples
+    &assets::Assets,
+    // DotEnv Examples
+    &dotenv::DotEnv,
+    // Cans Examples
+    &cans::CansTemplateEngine,
+    // DB Examples
+    &db::DbRedis,
+    // Fluxor Full Template Example
+    &fluxor::FluxorTemplate,
+];
+
+/// Looks up an example in the registry by its `--example` name or one of its aliases.
+pub fn find_example(name: &str) -> Option<&'static dyn Example> {
+    EXAMPLES
+        .iter()
+        .copied()
+        .find(|example| example.name() == name || example.aliases().contains(&name))
+}
+
+/// Renders the registry as a plain-text listing, grouped by category.
+pub fn examples_listing() -> String {
+    let mut output = String::new();
+
+    for category in CATEGORIES {
+        output.push_str(&format!("{}\n", category));
+
+        for example in EXAMPLES.iter().filter(|example| example.category() == *category) {
+            output.push_str(&format!("  {:<24}{}\n", example.name(), example.description()));
+            if !example.aliases().is_empty() {
+                output.push_str(&format!("  {:<24}aliases: {}\n", "", example.aliases().join(", ")));
+            }
+            output.push_str(&format!("  {:<24}files: {}\n", "", example.files().join(", ")));
+        }
+
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Renders the registry as the `## All Examples` section of the README.
+///
+/// # Examples
+///
+/// ```rust
+/// // The README section must always match the registry.
+/// let readme = include_str!("../../README.md");
+/// assert!(readme.contains(&fluxor_cli::examples_markdown()));
+/// ```
+pub fn examples_markdown() -> String {
+    let mut output = String::from("## All Examples\n");
+
+    for category in CATEGORIES {
+        output.push_str(&format!("\n### {}\n\n", category));
+
+        for example in EXAMPLES.iter().filter(|example| example.category() == *category) {
+            output.push_str(&format!("- {} — {}\n", example.name(), example.description()));
+        }
+    }
+
+    output
+}
@@ -0,0 +1,309 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::{Component, Path, PathBuf};
+use std::process::Command as ProcessCommand;
+
+use toml_edit::Item;
+
+use crate::error::{FluxorCliError, Result};
+
+/// A step run in the project directory after `fluxor new` generated it.
+///
+/// Template manifests list their hooks in order under `[template]`:
+///
+/// ```toml
+/// [template]
+/// hooks = [
+///     "git-init",
+///     "cargo-fmt",
+///     "cargo-generate-lockfile",
+///     { copy = ".env.example", to = ".env" },
+///     { run = "npm install --prefix web" },
+/// ]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Hook {
+    /// `git init`, skipped when the project is already inside a git work tree.
+    GitInit,
+    /// `cargo fmt`.
+    CargoFmt,
+    /// `cargo generate-lockfile`.
+    GenerateLockfile,
+    /// Copies a file of the project, e.g. `.env.example` to `.env`, unless the target exists.
+    Copy { from: PathBuf, to: PathBuf },
+    /// A shell command; commands of third-party templates are only run after a confirmation.
+    Run(String),
+}
+
+impl fmt::Display for Hook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hook::GitInit => write!(f, "git init"),
+            Hook::CargoFmt => write!(f, "cargo fmt"),
+            Hook::GenerateLockfile => write!(f, "cargo generate-lockfile"),
+            Hook::Copy { from, to } => write!(f, "copy {} to {}", from.display(), to.display()),
+            Hook::Run(command) => write!(f, "{}", command),
+        }
+    }
+}
+
+impl Hook {
+    /// Reads a hook from a `hooks` array entry of a template manifest.
+    pub(crate) fn from_manifest(item: &Item) -> std::result::Result<Self, String> {
+        if let Some(name) = item.as_str() {
+            return match name {
+                "git-init" => Ok(Hook::GitInit),
+                "cargo-fmt" => Ok(Hook::CargoFmt),
+                "cargo-generate-lockfile" => Ok(Hook::GenerateLockfile),
+                _ => Err(format!(
+                    "unknown hook '{}'; expected git-init, cargo-fmt, cargo-generate-lockfile, {{ copy = ..., to = ... }} or {{ run = ... }}",
+                    name
+                )),
+            };
+        }
+
+        let table = item.as_table_like().ok_or_else(|| "hooks must be strings or inline tables".to_string())?;
+        let text = |key: &str| table.get(key).and_then(Item::as_str);
+        let relative = |path: &str| {
+            let path = PathBuf::from(path);
+            if path.components().all(|component| matches!(component, Component::Normal(_))) {
+                Ok(path)
+            } else {
+                Err(format!("the hook path '{}' must be relative to the project", path.display()))
+            }
+        };
+
+        match (text("run"), text("copy"), text("to")) {
+            (Some(command), None, None) if !command.trim().is_empty() => Ok(Hook::Run(command.to_string())),
+            (None, Some(from), Some(to)) => Ok(Hook::Copy {
+                from: relative(from)?,
+                to: relative(to)?,
+            }),
+            _ => Err("hook tables must be { run = \"command\" } or { copy = \"from\", to = \"to\" }".to_string()),
+        }
+    }
+}
+
+/// Options of [`run_hooks`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HookOptions {
+    /// The hooks come from a built-in example rather than a third-party template.
+    pub trusted: bool,
+    /// Run the shell commands of third-party templates without asking.
+    pub yes: bool,
+}
+
+/// Runs `hooks` in order in the project directory `root`, stopping at the first failure.
+///
+/// Before the shell commands of an untrusted template run, they are listed and the user is
+/// asked to confirm; without a terminal they are skipped unless `yes` is set.
+pub fn run_hooks(hooks: &[Hook], root: &Path, options: HookOptions) -> Result<()> {
+    let commands: Vec<&Hook> = hooks.iter().filter(|hook| matches!(hook, Hook::Run(_))).collect();
+    let run_commands = options.trusted || options.yes || commands.is_empty() || confirm(&commands)?;
+
+    for hook in hooks {
+        let failed = |message: String| FluxorCliError::HookFailed {
+            hook: hook.to_string(),
+            message,
+        };
+
+        match hook {
+            Hook::GitInit => {
+                if !is_installed("git") {
+                    println!("Skipped `git init`: git was not found.");
+                    continue;
+                }
+                if command_output(root, "git", &["rev-parse", "--is-inside-work-tree"]).is_ok() {
+                    println!("Skipped `git init`: the project is already inside a git repository.");
+                    continue;
+                }
+                command_output(root, "git", &["init", "--quiet"]).map_err(failed)?;
+            }
+            Hook::CargoFmt => {
+                command_output(root, "cargo", &["fmt"]).map_err(failed)?;
+            }
+            Hook::GenerateLockfile => {
+                command_output(root, "cargo", &["generate-lockfile", "--quiet"]).map_err(failed)?;
+            }
+            Hook::Copy { from, to } => {
+                if root.join(to).exists() {
+                    println!("Skipped `{}`: {} already exists.", hook, to.display());
+                    continue;
+                }
+                fs::copy(root.join(from), root.join(to)).map_err(|e| failed(e.to_string()))?;
+            }
+            Hook::Run(command) => {
+                if !run_commands {
+                    println!("Skipped `{}`.", command);
+                    continue;
+                }
+                println!("Running `{}`...", command);
+                let status = shell(command)
+                    .current_dir(root)
+                    .status()
+                    .map_err(|e| failed(e.to_string()))?;
+                if !status.success() {
+                    return Err(failed(status.to_string()));
+                }
+                continue;
+            }
+        }
+
+        println!("Ran `{}`.", hook);
+    }
+
+    Ok(())
+}
+
+/// Asks whether the shell commands of a third-party template may run.
+fn confirm(commands: &[&Hook]) -> Result<bool> {
+    if !io::stdin().is_terminal() {
+        println!("The template wants to run shell commands; pass --yes to allow them:");
+        for command in commands {
+            println!("  {}", command);
+        }
+        return Ok(false);
+    }
+
+    println!("The template wants to run these shell commands:");
+    for command in commands {
+        println!("  {}", command);
+    }
+    print!("Run them? [y/N] ");
+    io::stdout().flush().map_err(|source| FluxorCliError::io("<stdout>", source))?;
+
+    let mut answer = String::new();
+    io::stdin()
+        .lock()
+        .read_line(&mut answer)
+        .map_err(|source| FluxorCliError::io("<stdin>", source))?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Whether `program` can be started, i.e. it is on the `PATH`.
+fn is_installed(program: &str) -> bool {
+    ProcessCommand::new(program)
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// Runs `program` in `dir` without showing its output, returning the first line of its error.
+fn command_output(dir: &Path, program: &str, args: &[&str]) -> std::result::Result<(), String> {
+    let output = ProcessCommand::new(program)
+        .current_dir(dir)
+        .args(args)
+        .output()
+        .map_err(|e| format!("failed to run {}: {}", program, e))?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(stderr.lines().next().map(str::trim).unwrap_or("failed").to_string())
+    }
+}
+
+#[cfg(unix)]
+fn shell(command: &str) -> ProcessCommand {
+    let mut shell = ProcessCommand::new("sh");
+    shell.args(["-c", command]);
+    shell
+}
+
+#[cfg(not(unix))]
+fn shell(command: &str) -> ProcessCommand {
+    let mut shell = ProcessCommand::new("cmd");
+    shell.args(["/C", command]);
+    shell
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An empty directory under the system temp directory, unique to `name`.
+    fn project_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("fluxor-hooks-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn hook(manifest: &str) -> std::result::Result<Hook, String> {
+        let document = format!("hook = {}", manifest).parse::<toml_edit::DocumentMut>().unwrap();
+        Hook::from_manifest(&document["hook"])
+    }
+
+    #[test]
+    fn hooks_are_read_from_the_manifest() {
+        assert_eq!(hook("\"git-init\""), Ok(Hook::GitInit));
+        assert_eq!(hook("\"cargo-fmt\""), Ok(Hook::CargoFmt));
+        assert_eq!(
+            hook("{ copy = \".env.example\", to = \".env\" }"),
+            Ok(Hook::Copy {
+                from: PathBuf::from(".env.example"),
+                to: PathBuf::from(".env"),
+            })
+        );
+        assert_eq!(hook("{ run = \"make\" }"), Ok(Hook::Run("make".to_string())));
+        assert!(hook("\"npm-install\"").is_err());
+        assert!(hook("{ copy = \".env.example\", to = \"../.env\" }").is_err());
+        assert!(hook("{ run = \"make\", copy = \"a\" }").is_err());
+    }
+
+    #[test]
+    fn copy_hooks_keep_existing_files() {
+        let dir = project_dir("copy");
+        fs::write(dir.join(".env.example"), "PORT=8080\n").unwrap();
+        let copy = |to: &str| Hook::Copy {
+            from: PathBuf::from(".env.example"),
+            to: PathBuf::from(to),
+        };
+        fs::write(dir.join(".env.local"), "PORT=3000\n").unwrap();
+
+        run_hooks(&[copy(".env"), copy(".env.local")], &dir, HookOptions::default()).unwrap();
+
+        assert_eq!(fs::read_to_string(dir.join(".env")).unwrap(), "PORT=8080\n");
+        assert_eq!(fs::read_to_string(dir.join(".env.local")).unwrap(), "PORT=3000\n");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn commands_run_in_the_project_and_stop_at_the_first_failure() {
+        let dir = project_dir("run");
+        let hooks = [
+            Hook::Run("touch first".to_string()),
+            Hook::Run("exit 3".to_string()),
+            Hook::Run("touch second".to_string()),
+        ];
+        let options = HookOptions {
+            yes: true,
+            ..HookOptions::default()
+        };
+
+        let error = run_hooks(&hooks, &dir, options).unwrap_err();
+
+        assert!(matches!(error, FluxorCliError::HookFailed { ref hook, .. } if hook == "exit 3"));
+        assert!(dir.join("first").exists());
+        assert!(!dir.join("second").exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn a_missing_copy_source_fails() {
+        let dir = project_dir("missing");
+        let hooks = [Hook::Copy {
+            from: PathBuf::from(".env.example"),
+            to: PathBuf::from(".env"),
+        }];
+
+        assert!(matches!(
+            run_hooks(&hooks, &dir, HookOptions::default()),
+            Err(FluxorCliError::HookFailed { .. })
+        ));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,296 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use cargo_metadata::{Edition, MetadataCommand};
+use toml_edit::{ArrayOfTables, DocumentMut, Item, value};
+
+use crate::error::{FluxorCliError, Result};
+use crate::sink::{DiskSink, MemorySink, ProjectSink};
+use crate::{NewProject, generate_project};
+
+/// Options of `fluxor init`, which scaffolds an example into an existing directory.
+///
+/// If the directory already holds a Cargo package, its name is kept and the example's
+/// dependencies are merged into its Cargo.toml; otherwise the directory name is used and a
+/// new Cargo.toml is written. Existing files under `src` are never overwritten without `force`;
+/// other existing files (README.md, .gitignore, ...) are kept.
+#[derive(Debug, Clone)]
+pub struct InitProject<'a> {
+    /// The fluxor version: `latest`, an exact version or a semver requirement.
+    pub version: &'a str,
+    /// The `--example` name or alias.
+    pub example: &'a str,
+    /// Resolve `latest` from the local Cargo registry cache only.
+    pub offline: bool,
+    /// Let `latest` resolve to pre-release versions.
+    pub allow_prerelease: bool,
+    /// Versions requested with `--dep name=version`.
+    pub dependencies: &'a [(String, String)],
+    /// A `--template` directory or git URL used instead of `example`.
+    pub template: Option<&'a str>,
+    /// Template variables set with `--set key=value`.
+    pub values: &'a [(String, String)],
+    /// A `--values` file of template variables.
+    pub values_file: Option<&'a Path>,
+    /// Overwrite existing files.
+    pub force: bool,
+}
+
+impl InitProject<'_> {
+    /// Scaffolds the example into `directory`.
+    pub fn init(&self, directory: &Path) -> Result<()> {
+        let manifest_path = directory.join("Cargo.toml");
+        let existing = if manifest_path.exists() {
+            Some(read_package_name(&manifest_path)?)
+        } else {
+            None
+        };
+
+        let name = match &existing {
+            Some(name) => name.clone(),
+            None => directory
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+        };
+
+        let project = NewProject {
+            name: &name,
+            version: self.version,
+            example: self.example,
+            offline: self.offline,
+            allow_prerelease: self.allow_prerelease,
+            dependencies: self.dependencies,
+            template: self.template,
+            values: self.values,
+            values_file: self.values_file,
+            // Hooks only run for `fluxor new`
+            no_hooks: true,
+            yes: false,
+        }
+        .resolve()?;
+
+        let mut generated = MemorySink::new();
+        generate_project(&mut generated, Path::new(""), &project)?;
+
+        // Merge the dependencies into an existing Cargo.toml instead of replacing it
+        let cargo_toml_path = PathBuf::from("Cargo.toml");
+        // Templates can leave out their Cargo.toml through a condition
+        let generated_toml = generated.files().get(&cargo_toml_path).ok_or_else(|| FluxorCliError::Manifest {
+            path: manifest_path.clone(),
+            message: format!("{} did not generate a Cargo.toml", project.source.describe()),
+        })?;
+        let cargo_toml = match existing {
+            Some(_) => {
+                let current = fs::read_to_string(&manifest_path).map_err(|source| FluxorCliError::io(&manifest_path, source))?;
+                merge_dependencies(&manifest_path, &current, &String::from_utf8_lossy(generated_toml))?
+            }
+            None => generated_toml.clone(),
+        };
+
+        // Decide what to do with every file before writing anything
+        let mut writes = Vec::new();
+        let mut kept = Vec::new();
+        let mut conflicts = Vec::new();
+
+        for (path, contents) in generated.files() {
+            if *path == cargo_toml_path {
+                continue;
+            }
+
+            match fs::read(directory.join(path)) {
+                Err(_) => writes.push(path),
+                Ok(current) if current == *contents => {}
+                Ok(_) if self.force => writes.push(path),
+                Ok(_) if path.starts_with("src") => conflicts.push(path.clone()),
+                Ok(_) => kept.push(path),
+            }
+        }
+
+        if !conflicts.is_empty() {
+            return Err(FluxorCliError::WouldOverwrite(conflicts));
+        }
+
+        let mut sink = DiskSink::new(directory);
+        for dir in generated.dirs() {
+            sink.create_dir(dir)?;
+        }
+        for path in writes {
+            sink.write_file(path, &generated.files()[path])?;
+            if generated.is_executable(path) {
+                sink.set_executable(path)?;
+            }
+        }
+        sink.write_file(&cargo_toml_path, &cargo_toml)?;
+
+        for path in kept {
+            println!("Kept existing '{}'.", path.display());
+        }
+
+        println!(
+            "Fluxor project '{}' initialized in '{}' using {}.",
+            project.package_name,
+            directory.display(),
+            project.source.describe()
+        );
+
+        Ok(())
+    }
+}
+
+/// Reads the package name of the Cargo.toml at `manifest_path`, rejecting editions Fluxor cannot use.
+fn read_package_name(manifest_path: &Path) -> Result<String> {
+    let manifest_error = |message: String| FluxorCliError::Manifest {
+        path: manifest_path.to_path_buf(),
+        message,
+    };
+
+    let metadata = MetadataCommand::new()
+        .manifest_path(manifest_path)
+        .no_deps()
+        .exec()
+        .map_err(|e| manifest_error(e.to_string()))?;
+
+    let canonical = manifest_path.canonicalize().map_err(|source| FluxorCliError::io(manifest_path, source))?;
+    let package = metadata
+        .packages
+        .iter()
+        .find(|package| package.manifest_path.as_std_path() == canonical)
+        .ok_or_else(|| manifest_error("no [package] section; run `fluxor init` inside a package, not a virtual workspace".to_string()))?;
+
+    if matches!(package.edition, Edition::E2015 | Edition::E2018) {
+        return Err(manifest_error(format!(
+            "edition {} is not supported by Fluxor; set `edition = \"2021\"` or later",
+            package.edition
+        )));
+    }
+
+    Ok(package.name.to_string())
+}
+
+/// Copies the `[dependencies]` and `[[bin]]` targets of `generated` into `current`, keeping its
+/// formatting and comments.
+///
+/// A dependency that is already a table (e.g. `{ version = "1", features = [...] }`) only gets
+/// its `version`, and the keys it does not have yet, from the generated one. Binary targets are
+/// added unless `current` already has one with the same name.
+fn merge_dependencies(manifest_path: &Path, current: &str, generated: &str) -> Result<Vec<u8>> {
+    let manifest_error = |message: String| FluxorCliError::Manifest {
+        path: manifest_path.to_path_buf(),
+        message,
+    };
+
+    let mut document = current.parse::<DocumentMut>().map_err(|e| manifest_error(e.to_string()))?;
+    let generated = generated.parse::<DocumentMut>().map_err(|e| manifest_error(e.to_string()))?;
+
+    if let Some(generated_dependencies) = generated.get("dependencies").and_then(Item::as_table_like) {
+        let dependencies = document
+            .entry("dependencies")
+            .or_insert_with(toml_edit::table)
+            .as_table_like_mut()
+            .ok_or_else(|| manifest_error("`dependencies` is not a table".to_string()))?;
+
+        for (name, item) in generated_dependencies.iter() {
+            match dependencies.get_mut(name).and_then(Item::as_table_like_mut) {
+                Some(table) => match item.as_table_like() {
+                    Some(generated_table) => {
+                        let added = generated_table.iter().any(|(key, _)| !table.contains_key(key));
+                        for (key, item) in generated_table.iter() {
+                            if key == "version" || !table.contains_key(key) {
+                                table.insert(key, item.clone());
+                            }
+                        }
+                        if added {
+                            table.fmt();
+                        }
+                    }
+                    None => {
+                        if let Some(version) = item.as_str() {
+                            table.insert("version", value(version));
+                        }
+                    }
+                },
+                None => {
+                    dependencies.insert(name, item.clone());
+                }
+            }
+        }
+    }
+
+    if let Some(generated_bins) = generated.get("bin").and_then(Item::as_array_of_tables) {
+        let bins = document
+            .entry("bin")
+            .or_insert_with(|| Item::ArrayOfTables(ArrayOfTables::new()))
+            .as_array_of_tables_mut()
+            .ok_or_else(|| manifest_error("`bin` is not an array of tables".to_string()))?;
+
+        for bin in generated_bins.iter() {
+            let name = bin.get("name").and_then(Item::as_str);
+            if !bins.iter().any(|existing| existing.get("name").and_then(Item::as_str) == name) {
+                bins.push(bin.clone());
+            }
+        }
+    }
+
+    Ok(document.to_string().into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn merged(current: &str, generated: &str) -> String {
+        String::from_utf8(merge_dependencies(Path::new("Cargo.toml"), current, generated).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn dependencies_are_added_keeping_comments() {
+        let current = "[package]\nname = \"app\" # the app\n\n[dependencies]\nserde = \"1\"\n";
+        let generated = "[package]\nname = \"app\"\n\n[dependencies]\nfluxor = \"1.2.0\"\n";
+
+        assert_eq!(
+            merged(current, generated),
+            "[package]\nname = \"app\" # the app\n\n[dependencies]\nserde = \"1\"\nfluxor = \"1.2.0\"\n"
+        );
+        assert_eq!(
+            merged("[package]\nname = \"app\"\n", generated),
+            "[package]\nname = \"app\"\n\n[dependencies]\nfluxor = \"1.2.0\"\n"
+        );
+    }
+
+    #[test]
+    fn table_dependencies_only_get_their_version_updated() {
+        let current = "[dependencies]\nfluxor = { version = \"1.1.2\", default-features = false }\n";
+
+        assert_eq!(
+            merged(current, "[dependencies]\nfluxor = \"1.2.0\"\n"),
+            "[dependencies]\nfluxor = { version = \"1.2.0\", default-features = false }\n"
+        );
+        assert_eq!(
+            merged(current, "[dependencies]\nfluxor = { version = \"1.2.0\", features = [\"tls\"] }\n"),
+            "[dependencies]\nfluxor = { version = \"1.2.0\", default-features = false, features = [\"tls\"] }\n"
+        );
+    }
+
+    #[test]
+    fn table_dependencies_without_a_version_keep_theirs() {
+        let current = "[dependencies]\nfluxor = { version = \"1.1.2\" }\n";
+
+        assert_eq!(
+            merged(current, "[dependencies]\nfluxor = { git = \"https://github.com/dr-montasir/fluxor\" }\n"),
+            "[dependencies]\nfluxor = { version = \"1.1.2\", git = \"https://github.com/dr-montasir/fluxor\" }\n"
+        );
+    }
+
+    #[test]
+    fn binary_targets_are_added_once() {
+        let generated = "[dependencies]\nfluxor = \"1.2.0\"\n\n[[bin]]\nname = \"server\"\npath = \"src/server.rs\"\n";
+
+        let merged_once = merged("[package]\nname = \"app\"\n", generated);
+        assert_eq!(
+            merged_once,
+            "[package]\nname = \"app\"\n\n[dependencies]\nfluxor = \"1.2.0\"\n\n[[bin]]\nname = \"server\"\npath = \"src/server.rs\"\n"
+        );
+        assert_eq!(merged(&merged_once, generated), merged_once);
+    }
+}
@@ -1,18 +1,45 @@
 #![doc(html_logo_url = "https://github.com/dr-montasir/fluxor_cli/raw/HEAD/fluxor-icon-64x64.svg")]
 #![doc = r"<div align='center'><a href='https://github.com/dr-montasir/fluxor_cli' target='_blank'><img src='https://github.com/dr-montasir/fluxor_cli/raw/HEAD/fluxor-icon-64x64.svg' alt='Fluxor CLI' width='80' height='auto' /></a><br><br><a href='https://github.com/dr-montasir/fluxor_cli' target='_blank'>FLUXOR</a><br><br>Fluxor_cli is the command-line interface for the Fluxor web framework, enabling rapid project scaffolding and management for Rust applications focused on data science and computing.</div>"]
 
+pub mod error;
 pub mod utils;
 pub mod metadata;
+pub mod sink;
+pub mod staging;
+pub mod version;
+pub mod init;
+pub mod add;
+pub mod routes;
+pub mod check;
+pub mod openapi;
+pub mod server;
+pub mod dev;
+pub mod doctor;
+pub mod upgrade;
+pub mod template;
+pub mod hooks;
 mod examples;
 
 pub use clap::Parser;
-use regex::Regex;
-use std::fs;
-use std::path::Path;
-use std::process::Command as ProcessCommand;
-
+pub use error::{FluxorCliError, Result};
+pub use examples::{DependencyVersions, Example, CATEGORIES, EXAMPLES, find_example, examples_listing, examples_markdown};
+pub use version::{VersionResolver, VersionSpec, fetch_latest_version};
+pub use init::InitProject;
+pub use add::{AddComponent, AddPage, AddRoute, RouteKind};
+pub use routes::{RouteFormat, RouteTable};
+pub use check::{RouteIssue, check_routes};
+pub use openapi::{OpenApi, OpenApiFormat};
+pub use server::ServerConfig;
+pub use dev::DevServer;
+pub use upgrade::Upgrade;
+pub use template::Template;
+use std::path::{Path, PathBuf};
+
+use sink::{ArchiveSink, DiskSink, MemorySink, ProjectSink};
+use hooks::{Hook, HookOptions, run_hooks};
+use staging::StagingDir;
+use template::variables::{Values, resolve_values};
 use utils::*;
-use examples::*;
 
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
@@ -25,364 +52,491 @@ pub struct Args {
 pub enum Commands {
     New {
         name: String,
-        #[clap(short, long, default_value = "latest")]
-        version: String,
-        #[clap(short, long, default_value = "helloworld")]
-        example: String,
+        #[clap(flatten)]
+        project: ProjectArgs,
+        /// Print the files that would be generated without writing anything to disk.
+        #[clap(long)]
+        dry_run: bool,
+        /// With `--dry-run`, also print the contents of every generated file.
+        #[clap(long, requires = "dry_run")]
+        show_contents: bool,
+        /// Pack the project into a .tar, .tar.gz, .tgz or .zip archive instead of a directory.
+        #[clap(long, value_name = "FILE", conflicts_with = "dry_run")]
+        archive: Option<PathBuf>,
+        /// Do not run the post-generation hooks (`git init`, the template's hooks).
+        #[clap(long)]
+        no_hooks: bool,
+        /// Run the shell commands of a template's hooks without asking.
+        #[clap(long, conflicts_with = "no_hooks")]
+        yes: bool,
+    },
+    /// Turn the current directory (empty, or an existing Cargo package) into a Fluxor app.
+    Init {
+        #[clap(flatten)]
+        project: ProjectArgs,
+        /// Overwrite existing files instead of refusing to run.
+        #[clap(long)]
+        force: bool,
+    },
+    /// Add code to an existing Fluxor project.
+    Add {
+        #[clap(subcommand)]
+        command: AddCommands,
+    },
+    /// Print every route registered in the project, with its handler and source location.
+    Routes {
+        /// Print an aligned table, or JSON for tooling.
+        #[clap(long, value_enum, default_value = "table")]
+        format: RouteFormat,
+    },
+    /// Lint the project, exiting with a non-zero code when problems are found.
+    Check {
+        #[clap(subcommand)]
+        command: CheckCommands,
+    },
+    /// Generate an OpenAPI 3.1 document from the project's routes and `/// @response` tags.
+    #[clap(name = "openapi")]
+    OpenApi {
+        #[clap(long, value_enum, default_value = "yaml")]
+        format: OpenApiFormat,
+        /// Write the document to FILE instead of printing it.
+        #[clap(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+    /// Run the project, rebuilding and restarting it when its sources change.
+    Dev {
+        /// The binary to run when the package has several.
+        #[clap(long)]
+        bin: Option<String>,
+    },
+    /// Check the toolchain, the crates.io index and the current project.
+    Doctor,
+    /// Bump fluxor (and crator, where used) in Cargo.toml, printing the diff.
+    Upgrade {
+        /// The fluxor version: `latest`, an exact version (`1.1.2`) or a requirement (`^1.1`).
+        #[clap(long, default_value = "latest")]
+        to: String,
+        /// Allow upgrades to semver-incompatible releases, e.g. crator 0.7 to 1.x.
+        #[clap(long)]
+        major: bool,
+        /// Upgrade every member of the workspace instead of the current package.
+        #[clap(long)]
+        workspace: bool,
+        /// Resolve `latest` from the local Cargo registry cache only, never the network.
+        #[clap(long)]
+        offline: bool,
+    },
+    /// Manage the git templates cached for `fluxor new --template`.
+    Templates {
+        #[clap(subcommand)]
+        command: TemplatesCommands,
+    },
+    /// List every example accepted by `fluxor new --example`.
+    List {
+        /// Print the list as the README `All Examples` markdown section.
+        #[clap(long)]
+        markdown: bool,
     },
 }
 
-pub fn fetch_latest_version(crate_name: &str) -> Result<String, Box<dyn std::error::Error>> {
-    let output = ProcessCommand::new("cargo")
-        .arg("search")
-        .arg(crate_name)
-        .output()?;
-
-    if !output.status.success() {
-        return Err(format!(
-            "Failed to fetch latest version of {}: {}",
-            crate_name,
-            String::from_utf8_lossy(&output.stderr)
-        )
-        .into());
-    }
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    println!("Cargo search output: {}", stdout); // Debug print
+#[derive(Parser, Debug)]
+pub enum AddCommands {
+    /// Add a route handler and register it in `setup_routes`, e.g. `fluxor add route GET /api/users/<id>`.
+    Route {
+        /// The HTTP method (GET, POST, PUT, DELETE, ...).
+        method: String,
+        /// The route path; `<name>` segments are parameters.
+        path: String,
+        /// Whether the handler belongs in `src/routes/api` or `src/routes/pages`.
+        #[clap(long, value_enum, default_value = "api")]
+        kind: RouteKind,
+        /// The handler function name (default: derived from the method and path, e.g. `get_users_by_id`).
+        #[clap(long)]
+        handler: Option<String>,
+    },
+    /// Add a page rendered with the `layout` component of the fluxor-template example, with nav links.
+    Page {
+        /// The page name, e.g. `pricing`.
+        name: String,
+        /// The route path (default: `/<name>`).
+        #[clap(long)]
+        path: Option<String>,
+        /// The title shown in the navigation and the page heading (default: derived from the name).
+        #[clap(long)]
+        title: Option<String>,
+    },
+    /// Add a cans template component re-exported from `components/mod.rs`.
+    Component {
+        /// The component name, e.g. `card`.
+        name: String,
+        /// Comma-separated parameters, each rendered through a `{{param}}` placeholder.
+        #[clap(long, value_delimiter = ',')]
+        params: Vec<String>,
+    },
+}
 
-    // Use a regex to extract the version number
-    let re = Regex::new(r#"(\d+\.\d+\.\d+)"#).unwrap();
-    let version = stdout
-        .lines()
-        .find(|line| line.contains(crate_name))
-        .and_then(|line| re.find(line))
-        .map(|version| version.as_str())
-        .ok_or_else(|| format!("Crate '{}' not found", crate_name))?;
+#[derive(Parser, Debug)]
+pub enum CheckCommands {
+    /// Report duplicate and shadowed routes, unused handlers and undefined handlers.
+    Routes,
+}
 
-    println!("Parsed version: {}", version); // Debug print
+#[derive(Parser, Debug)]
+pub enum TemplatesCommands {
+    /// Fetch the latest commits of every git template cached in `~/.cache/fluxor/templates`.
+    Update,
+}
 
-    Ok(version.to_string())
+/// The example and dependency versions shared by `fluxor new` and `fluxor init`.
+#[derive(clap::Args, Debug)]
+pub struct ProjectArgs {
+    /// The fluxor version: `latest`, an exact version (`1.1.2`) or a requirement (`^1.1`, `>=1, <2`).
+    #[clap(short, long, default_value = "latest")]
+    pub version: String,
+    #[clap(short, long, default_value = "helloworld")]
+    pub example: String,
+    /// Generate the project from a template with a `fluxor-template.toml` manifest instead of a
+    /// built-in example: a local directory or a git URL such as `git+https://host/repo.git#v2`.
+    #[clap(long, value_name = "DIR|URL", conflicts_with = "example")]
+    pub template: Option<String>,
+    /// Set a variable of the template, e.g. `--set with_db=true`; may be repeated.
+    #[clap(long = "set", value_name = "KEY=VALUE", requires = "template", value_parser = parse_template_value)]
+    pub values: Vec<(String, String)>,
+    /// Read template variables from the `key = value` pairs of a TOML file; `--set` takes precedence.
+    #[clap(long = "values", value_name = "FILE", requires = "template")]
+    pub values_file: Option<PathBuf>,
+    /// Resolve `latest` versions from the local Cargo registry cache only, never the network.
+    #[clap(long)]
+    pub offline: bool,
+    /// Let `latest` resolve to pre-release versions such as `2.0.0-beta.1`.
+    #[clap(long)]
+    pub allow_prerelease: bool,
+    /// Pin a dependency of the example, e.g. `--dep crator=0.5.0`; may be repeated.
+    #[clap(long = "dep", value_name = "NAME=VERSION", value_parser = parse_dependency_override)]
+    pub deps: Vec<(String, String)>,
 }
 
-// Fetch dependencies crate version if "latest" is specified
-pub fn get_crate_version(version: &str, dep_crate_name: &str) -> String {
-    if version == "latest" {
-        fetch_latest_version(dep_crate_name).expect(&format!("Failed to fetch latest version of {}", dep_crate_name))
-    } else {
-        version.to_string()
+impl ProjectArgs {
+    /// The [`NewProject`] options for a project called `name`.
+    pub fn new_project<'a>(&'a self, name: &'a str) -> NewProject<'a> {
+        NewProject {
+            offline: self.offline,
+            allow_prerelease: self.allow_prerelease,
+            dependencies: &self.deps,
+            template: self.template.as_deref(),
+            values: &self.values,
+            values_file: self.values_file.as_deref(),
+            ..NewProject::new(name, &self.version, &self.example)
+        }
+    }
+
+    /// The [`InitProject`] options.
+    pub fn init_project(&self, force: bool) -> InitProject<'_> {
+        InitProject {
+            version: &self.version,
+            example: &self.example,
+            offline: self.offline,
+            allow_prerelease: self.allow_prerelease,
+            dependencies: &self.deps,
+            template: self.template.as_deref(),
+            values: &self.values,
+            values_file: self.values_file.as_deref(),
+            force,
+        }
     }
 }
 
-pub fn create_fluxor_web_project(name: &str, version: &str, example: &str) {
-    let package_name = to_crate_name(name);
-    let project_path = Path::new(&package_name);
+/// Splits a `--dep` value such as `crator=0.5.0` into the crate name and the requested version.
+///
+/// # Examples
+///
+/// ```rust
+/// use fluxor_cli::parse_dependency_override;
+///
+/// assert_eq!(parse_dependency_override("crator=^0.5"), Ok(("crator".to_string(), "^0.5".to_string())));
+/// assert!(parse_dependency_override("crator").is_err());
+/// ```
+pub fn parse_dependency_override(value: &str) -> std::result::Result<(String, String), String> {
+    match value.split_once('=') {
+        Some((name, version)) if !name.trim().is_empty() && !version.trim().is_empty() => {
+            Ok((name.trim().to_string(), version.trim().to_string()))
+        }
+        _ => Err(format!("expected NAME=VERSION, got '{}'", value)),
+    }
+}
 
-    // Check if project directory already exists
-    if project_path.exists() {
-        eprintln!("Project directory already exists.");
-        return;
+/// Splits a `--set` value such as `with_db=true` into the variable name and its value, which may be empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use fluxor_cli::parse_template_value;
+///
+/// assert_eq!(parse_template_value("title=My App"), Ok(("title".to_string(), "My App".to_string())));
+/// assert!(parse_template_value("=true").is_err());
+/// ```
+pub fn parse_template_value(value: &str) -> std::result::Result<(String, String), String> {
+    match value.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => Ok((name.trim().to_string(), value.to_string())),
+        _ => Err(format!("expected KEY=VALUE, got '{}'", value)),
     }
+}
 
-    // Create project directory
-    fs::create_dir_all(&project_path).expect("Failed to create project directory");
+// Fetch dependencies crate version if "latest" is specified
+pub fn get_crate_version(version: &str, dep_crate_name: &str) -> Result<String> {
+    VersionResolver::new(false).resolve(version, dep_crate_name)
+}
 
-    // Fetch fluxor version if "latest" is specified
-    let fluxor_version = if version == "latest" {
-        fetch_latest_version("fluxor").expect("Failed to fetch latest version of fluxor")
-    } else {
-        version.to_string()
-    };
+/// Options of `fluxor new`.
+#[derive(Debug, Clone)]
+pub struct NewProject<'a> {
+    /// The project name; it is converted to a crate name for the directory and package.
+    pub name: &'a str,
+    /// The fluxor version: `latest`, an exact version or a semver requirement.
+    pub version: &'a str,
+    /// The `--example` name or alias.
+    pub example: &'a str,
+    /// Resolve `latest` from the local Cargo registry cache only.
+    pub offline: bool,
+    /// Let `latest` resolve to pre-release versions.
+    pub allow_prerelease: bool,
+    /// Versions requested with `--dep name=version`; they take precedence over `version`.
+    pub dependencies: &'a [(String, String)],
+    /// A `--template` directory or git URL used instead of `example`.
+    pub template: Option<&'a str>,
+    /// Template variables set with `--set key=value`.
+    pub values: &'a [(String, String)],
+    /// A `--values` file of template variables.
+    pub values_file: Option<&'a Path>,
+    /// Skip the post-generation hooks.
+    pub no_hooks: bool,
+    /// Run the shell commands of a template's hooks without asking.
+    pub yes: bool,
+}
 
-    // create README.md
-    metadata::create_readme(project_path, &package_name);
+/// What a project is generated from.
+enum ProjectSource {
+    Example(&'static dyn Example),
+    Template(Template, Values),
+}
 
-    // Create Cargo.toml specific for the example
-    let cargo_toml = match example {
-        // Hello World Examples
-        "helloworld" => hello_world::hello_world_cargo_toml(&package_name, &fluxor_version),
-        "helloworld-api" => hello_world::hello_world_cargo_toml(&package_name, &fluxor_version),
-        "helloworld-api-server" => hello_world::hello_world_api_server_cargo_toml(&package_name, &fluxor_version),
-        // Routes Examples
-        "routes" => routes::routes_cargo_toml(&package_name, &fluxor_version),
-        "routes-project" => routes::routes_cargo_toml(&package_name, &fluxor_version),
-        // Assets Examples
-        "assets" => assets::assets_cargo_toml(&package_name, &fluxor_version),
-        // DotEnv Examples
-        "dotenv" => dotenv::dotenv_cargo_toml(&package_name, &fluxor_version),
-        "cans-template-engine" => cans::template_cargo_toml(&package_name, &fluxor_version),
-        // DB
-        "db-redis" => db::db_cargo_toml(&package_name, &fluxor_version),
-        // Fluxor Full Template Example
-        "fluxor-template" => fluxor::fluxor_template_cargo_toml(
-            &package_name, 
-            &get_crate_version("latest", "crator"), 
-            &get_crate_version("latest", "fluxor")
-        ),
-        _ => {
-            eprintln!("Unknown example specified: {}", example);
-            return;
+impl ProjectSource {
+    fn name(&self) -> &str {
+        match self {
+            ProjectSource::Example(example) => example.name(),
+            ProjectSource::Template(template, _) => &template.name,
         }
-    };
-
-    fs::write(project_path.join("Cargo.toml"), cargo_toml)
-        .expect("Failed to create Cargo.toml");
-
-    // Create src directory under the project
-    let src_path = project_path.join("src");
-    fs::create_dir_all(&src_path).expect("Failed to create src directory");
+    }
 
-    // Create src folders and files based on the specified example
-    match example {
-        // Hello World Examples
-        "helloworld" => {
-            // metadata files
-            hello_world::config_metadata(&project_path);
-
-            // src/main.rs
-            hello_world::hello_world_main_rs(&src_path);
+    fn dependencies(&self) -> Vec<&str> {
+        match self {
+            ProjectSource::Example(example) => example.dependencies().to_vec(),
+            ProjectSource::Template(template, _) => template.dependencies.iter().map(String::as_str).collect(),
         }
-        "helloworld-api" => {
-            // metadata files
-            hello_world::config_metadata(&project_path);
+    }
 
-            // src/main.rs
-            hello_world::hello_world_api_main_rs(&src_path);
+    fn default_version(&self, crate_name: &str) -> &str {
+        match self {
+            ProjectSource::Example(example) => example.default_version(crate_name),
+            ProjectSource::Template(..) => "latest",
         }
-        "helloworld-api-server" => {
-            // metadata files
-            hello_world::config_metadata(&project_path);
+    }
 
-            // src/server.rs
-            hello_world::hello_world_api_server_rs(&src_path);
+    fn hooks(&self) -> Vec<Hook> {
+        match self {
+            ProjectSource::Example(example) => example.hooks(),
+            ProjectSource::Template(template, _) => template.hooks.clone(),
         }
-        // Routes Examples
-        "routes" => {
-            // metadata files
-            routes::config_metadata(&project_path);
+    }
 
-            // src/main.rs
-            routes::routes_main_rs(&src_path);
-        }
-        "routes-project" => {
-            // metadata files
-            routes::config_metadata(&project_path);
-
-            // src
-            // src/main.rs
-            routes::routes_project_main_rs(&package_name, &src_path);
-            // src/lib.rs
-            routes::routes_project_lib_rs(&src_path);
-
-            // src/routes
-            // Create routes directory under the src folder
-            let src_routes_path = project_path.join("src/routes");
-            fs::create_dir_all(&src_routes_path).expect("Failed to create src/routes directory");
-            // src/routes/mod.rs
-            routes::routes_project_routes_mod_rs(&src_routes_path);
-            
-            // src/routes/api
-            // Create routes/api directory under the src folder
-            let src_routes_api_path = project_path.join("src/routes/api");
-            fs::create_dir_all(&src_routes_api_path).expect("Failed to create src/routes/api directory");
-            // src/routes/api/mod.rs
-            routes::routes_project_routes_api_mod_rs(&src_routes_api_path);
-            // src/routes/api/msg.rs
-            routes::routes_project_routes_api_msg_rs(&src_routes_api_path);
-            // src/routes/api/dynamic_route.rs
-            routes::routes_project_routes_api_dynamic_route_rs(&src_routes_api_path);
-
-            // src/routes/pages
-            // Create routes/pages directory under the src folder
-            let src_routes_pages_path = project_path.join("src/routes/pages");
-            fs::create_dir_all(&src_routes_pages_path).expect("Failed to create src/routes/pages directory");
-            // src/routes/pages/mod.rs
-            routes::routes_project_routes_pages_mod_rs(&src_routes_pages_path);
-            // src/routes/pages/home.rs
-            routes::routes_project_routes_pages_home_rs(&src_routes_pages_path);
-            // src/routes/pages/about.rs
-            routes::routes_project_routes_pages_about_rs(&src_routes_pages_path);
+    /// `the 'helloworld' example` or `the 'acme-starter' template`, for messages.
+    fn describe(&self) -> String {
+        match self {
+            ProjectSource::Example(example) => format!("the '{}' example", example.name()),
+            ProjectSource::Template(template, _) => format!("the '{}' template", template.name),
         }
-        // Assets Examples
-        "assets" => {
-            // metadata files
-            assets::config_metadata(&project_path);
-
-            // main.rs
-            assets::assets_main_rs(&src_path);
-
-            // assets
-            let assets_img_path = project_path.join("assets/img");
-            let assets_css_path = project_path.join("assets/css");
-            let assets_js_path = project_path.join("assets/js");
-            
-            fs::create_dir_all(&assets_img_path).expect("Failed to create sassets/img directory");
-            fs::create_dir_all(&assets_css_path).expect("Failed to create sassets/css directory");
-            fs::create_dir_all(&assets_js_path).expect("Failed to create sassets/js directory");
-            
-            assets::assets_img_fluxor_svg(&assets_img_path);
-            assets::assets_css_styels_css(&assets_css_path);
-            assets::assets_js_script_js(&assets_js_path);
+    }
+}
+
+/// A project whose name, example and dependency versions have been validated and resolved.
+struct ResolvedProject {
+    package_name: String,
+    source: ProjectSource,
+    versions: DependencyVersions,
+}
+
+impl<'a> NewProject<'a> {
+    /// Creates the options for `fluxor new <name> --version <version> --example <example>`.
+    pub fn new(name: &'a str, version: &'a str, example: &'a str) -> Self {
+        NewProject {
+            name,
+            version,
+            example,
+            offline: false,
+            allow_prerelease: false,
+            dependencies: &[],
+            template: None,
+            values: &[],
+            values_file: None,
+            no_hooks: false,
+            yes: false,
         }
-        // DotEnv Examples
-        "dotenv" => {
-            // metadata files
-            dotenv::config_metadata(&project_path);
+    }
 
-            // main.rs
-            dotenv::dotenv_main_rs(&src_path);
+    fn resolve(&self) -> Result<ResolvedProject> {
+        let package_name = to_crate_name(self.name);
+        if package_name.is_empty() {
+            return Err(FluxorCliError::InvalidName(self.name.to_string()));
         }
-        // Cans:
-        // Template Examples
-        "cans-template-engine" => {
-            // metadata files
-            cans::config_metadata(&project_path);
-
-            // main.rs
-            cans::template_main_rs(&src_path);
+
+        // Reject malformed versions before any lookup
+        VersionSpec::parse("fluxor", self.version)?;
+        for (crate_name, version) in self.dependencies {
+            VersionSpec::parse(crate_name, version)?;
         }
-        // DB
-        // REDIS DB
-        "db-redis" => {
-            // metadata files
-            db::config_redis_metadata(&project_path);
-
-            // main.rs
-            db::db_redis_main_rs(&src_path);
+
+        // Look up the example in the registry, or read the template, before touching the filesystem
+        let source = match self.template {
+            Some(template) => {
+                let template = Template::open(template, self.offline)?;
+                let values = resolve_values(&template.variables, self.values, self.values_file)?;
+                ProjectSource::Template(template, values)
+            }
+            None => ProjectSource::Example(
+                find_example(self.example).ok_or_else(|| FluxorCliError::UnknownExample(self.example.to_string()))?,
+            ),
+        };
+        let dependencies = source.dependencies();
+
+        if let Some((crate_name, _)) = self
+            .dependencies
+            .iter()
+            .find(|(crate_name, _)| !dependencies.contains(&crate_name.as_str()))
+        {
+            return Err(FluxorCliError::UnknownDependency {
+                example: source.name().to_string(),
+                crate_name: crate_name.clone(),
+                dependencies: dependencies.iter().map(|dependency| dependency.to_string()).collect(),
+            });
         }
-        // Fluxor
-        // Full Fluxor Template Example
-        "fluxor-template" => {
-            // paths
-            let assets_path = project_path.join("src/assets");
-            let assets_images_path = project_path.join("src/assets/images");
-            let assets_css_path = project_path.join("src/assets/css");
-            let assets_js_path = project_path.join("src/assets/js");
-            let components_path = project_path.join("src/components");
-            let components_scripts_path = project_path.join("src/components/scripts");
-            let db_path = project_path.join("src/db");
-            let ds_path = project_path.join("src/ds");
-            let helpers_path = project_path.join("src/helpers");
-            let routes_path = project_path.join("src/routes");
-            let routes_api_path = project_path.join("src/routes/api");
-            let routes_api_greeting_path = project_path.join("src/routes/api/greeting");
-            let routes_pages_path = project_path.join("src/routes/pages");
-            let validators_path = project_path.join("src/validators");
-
-            // metadata files
-            fluxor::config_metadata(&project_path);
-
-            // src
-
-            // src/main.rs
-            fluxor::fluxor_template_main_rs(&package_name, &src_path);
-            // src/lib.rs
-            fluxor::fluxor_template_lib_rs(&src_path);
-
-            // src/assets
-
-            // src/assets/images
-            fs::create_dir_all(assets_path.join("images")).expect("Failed to create assets/images directory");
-            // src/assets/css
-            fs::create_dir_all(assets_path.join("css")).expect("Failed to create assets/css directory");
-            // src/assets/js
-            fs::create_dir_all(assets_path.join("js")).expect("Failed to create assets/js directory");
-
-            // src/assets/manifest.json
-            fluxor::assets_manifest_json(&assets_path);
-            // src/assets/service-worker.js
-            fluxor::assets_service_worker_js(&assets_path);
-            // src/assets/images/logo.svg
-            fluxor::assets_images_logo_svg(&assets_images_path);
-            // src/assets/css/styles.css
-            fluxor::assets_css_styles_css(&assets_css_path);
-            // src/assets/js/alpine.min.js
-            fluxor::assets_js_alpine_min_js(&assets_js_path);
-
-            // src/components
-
-            // src/components/scripts
-            fs::create_dir_all(components_path.join("scripts")).expect("Failed to create components/scripts directory");
-
-            // src/components/scripts/mod.rs
-            fluxor::components_scripts_mod_rs(&components_scripts_path);
-            // src/components/scripts/sw_register.rs
-            fluxor::components_scripts_sw_register_rs(&components_scripts_path);
-            // src/components/badge.rs
-            fluxor::components_badge_rs(&components_path);
-            // src/components/footer.rs
-            fluxor::components_footer_rs(&components_path);
-            // src/components/head.rs
-            fluxor::components_head_rs(&components_path);
-            // src/components/header.rs
-            fluxor::components_header_rs(&components_path);
-            // src/components/layout.rs
-            fluxor::components_layout_rs(&components_path);
-            // src/components/logo.rs
-            fluxor::components_logo_rs(&components_path);
-            // src/components/mod.rs
-            fluxor::components_mod_rs(&components_path);
-            // src/components/nav.rs
-            fluxor::components_nav_rs(&components_path);
-            // src/components/notfound.rs
-            fluxor::components_notfound_rs(&components_path);
-
-            // src/db
-
-            fs::create_dir_all(&db_path).expect("Failed to create src/db directory");
-
-            // src/db/mod.rs
-            fluxor::db_mod_rs(&db_path);
-
-            // src/ds
-
-            fs::create_dir_all(&ds_path).expect("Failed to create src/ds directory");
-
-            // src/ds/mod.rs
-            fluxor::ds_mod_rs(&ds_path);
-
-            // src/helpers
-
-            fs::create_dir_all(&helpers_path).expect("Failed to create src/helpers directory");
-
-            // src/helpers/mod.rs
-            fluxor::helpers_mod_rs(&helpers_path);
-
-            // src/routes
-
-            fs::create_dir_all(&routes_path).expect("Failed to create src/routes directory");
-            
-            // src/routes/pages
-            fs::create_dir_all(routes_path.join("pages")).expect("Failed to create routes/pages directory");
-            // src/routes/api
-            fs::create_dir_all(routes_path.join("api")).expect("Failed to create routes/api directory");
-            // src/routes/api/greeting
-            fs::create_dir_all(routes_path.join("api/greeting")).expect("Failed to create routes/api/greeting directory");
-
-            // src/routes/mod.rs
-            fluxor::routes_mod_rs(&routes_path);
-            // src/routes/api/mod.rs
-            fluxor::routes_api_mod_rs(&routes_api_path);
-            // src/routes/api/greeting/mod.rs
-            fluxor::routes_api_greeting_mod_rs(&routes_api_greeting_path);
-            // src/routes/pages/mod.rs
-            fluxor::routes_pages_mod_rs(&routes_pages_path);
-            // src/routes/pages/home.rs
-            fluxor::routes_pages_home_rs(&routes_pages_path);
-            // src/routes/pages/analytics.rs
-            fluxor::routes_pages_analytics_rs(&routes_pages_path);
-            
-            // src/validators
-
-            fs::create_dir_all(&validators_path).expect("Failed to create src/validators directory");
-
-            // src/validators/mod.rs
-            fluxor::validators_mod_rs(&validators_path);
+
+        // Resolve dependency versions: `--dep` wins, then fluxor follows `--version` and the
+        // others use the default of the example, usually the latest release
+        let resolver = VersionResolver::new(self.offline).allow_prerelease(self.allow_prerelease);
+        let versions = dependencies
+            .iter()
+            .map(|&dependency| {
+                let requested = self
+                    .dependencies
+                    .iter()
+                    .rev()
+                    .find(|(crate_name, _)| crate_name == dependency)
+                    .map(|(_, version)| version.as_str())
+                    .unwrap_or(if dependency == "fluxor" { self.version } else { source.default_version(dependency) });
+                resolver.resolve(requested, dependency).map(|resolved| (dependency.to_string(), resolved))
+            })
+            .collect::<Result<DependencyVersions>>()?;
+
+        Ok(ResolvedProject { package_name, source, versions })
+    }
+
+    /// Generates the project in a new directory named after the package.
+    pub fn create(&self) -> Result<()> {
+        let package_name = to_crate_name(self.name);
+
+        // Check if project directory already exists
+        if Path::new(&package_name).exists() {
+            return Err(FluxorCliError::DirectoryExists(package_name.into()));
         }
-        _ => {
-            eprintln!("Unknown example specified: {}", example);
-            return;
+
+        let project = self.resolve()?;
+        let project_path = Path::new(&project.package_name);
+
+        // Generate the project in a staging directory so a failure never leaves a partial project behind
+        let staging = StagingDir::create(project_path)?;
+        generate_project(&mut DiskSink::new(staging.path()), Path::new(""), &project)?;
+
+        // Move the complete project into place
+        staging.commit()?;
+
+        println!(
+            "Fluxor project '{}' created successfully using {}.",
+            project.package_name,
+            project.source.describe()
+        );
+
+        if !self.no_hooks {
+            let options = HookOptions {
+                trusted: matches!(project.source, ProjectSource::Example(_)),
+                yes: self.yes,
+            };
+            run_hooks(&project.source.hooks(), project_path, options)?;
         }
+
+        Ok(())
     }
 
-    println!("Fluxor project '{}' created successfully using the '{}' example.", package_name, example);
+    /// Generates the project in memory with the same generators as [`NewProject::create`],
+    /// without touching the filesystem.
+    pub fn preview(&self) -> Result<MemorySink> {
+        let project = self.resolve()?;
+        let project_path = Path::new(&project.package_name);
+
+        let mut sink = MemorySink::new();
+        sink.create_dir(project_path)?;
+        generate_project(&mut sink, project_path, &project)?;
+
+        Ok(sink)
+    }
+
+    /// Generates the project into a tar, tar.gz or zip archive whose entries live under the
+    /// project directory name.
+    pub fn archive(&self, archive: &Path) -> Result<()> {
+        let mut sink = ArchiveSink::new(archive)?;
+
+        let project = self.resolve()?;
+        let project_path = Path::new(&project.package_name);
+
+        sink.create_dir(project_path)?;
+        generate_project(&mut sink, project_path, &project)?;
+        sink.finish()?;
+
+        println!(
+            "Fluxor project '{}' archived to '{}' using {}.",
+            project.package_name,
+            archive.display(),
+            project.source.describe()
+        );
+
+        Ok(())
+    }
+}
+
+/// Writes every file of the project into `project_path` of `sink`.
+fn generate_project(sink: &mut dyn ProjectSink, project_path: &Path, project: &ResolvedProject) -> Result<()> {
+    let ResolvedProject { package_name, source, versions } = project;
+
+    let example = match source {
+        ProjectSource::Example(example) => example,
+        // Templates bring their own Cargo.toml and README.md
+        ProjectSource::Template(template, values) => {
+            return template.generate(sink, project_path, package_name, versions, values);
+        }
+    };
+
+    // create README.md
+    metadata::create_readme(sink, project_path, package_name)?;
+
+    // Create Cargo.toml specific for the example
+    sink.write_file(&project_path.join("Cargo.toml"), example.cargo_toml(package_name, versions).as_bytes())?;
+
+    // Create src directory under the project
+    sink.create_dir(&project_path.join("src"))?;
+
+    // Create src folders and files based on the specified example
+    example.generate(sink, project_path, package_name)
+}
+
+pub fn create_fluxor_web_project(name: &str, version: &str, example: &str) -> Result<()> {
+    NewProject::new(name, version, example).create()
 }
@@ -1,12 +1,111 @@
-use fluxor_cli::{Args, Commands, create_fluxor_web_project};
+use std::env;
+use std::process;
+
+use fluxor_cli::{AddCommands, AddComponent, AddPage, AddRoute, Args, CheckCommands, Commands, DevServer, FluxorCliError, NewProject, OpenApi, RouteTable, TemplatesCommands, Upgrade, examples_listing, examples_markdown};
 use fluxor_cli::Parser;
 
 fn main () {
    let args = Args::parse();
 
-   match args.command {
-      Commands::New { name, version, example } => {
-         create_fluxor_web_project(&name, &version, &example);
+   let result = match args.command {
+      Commands::New { name, project, dry_run, show_contents, archive, no_hooks, yes } => {
+         let project = NewProject { no_hooks, yes, ..project.new_project(&name) };
+
+         if dry_run {
+            project.preview().map(|sink| {
+               print!("{}", sink.tree());
+
+               if show_contents {
+                  for (path, contents) in sink.files() {
+                     println!("\n==> {} <==", path.display());
+                     println!("{}", String::from_utf8_lossy(contents));
+                  }
+               }
+            })
+         } else if let Some(archive) = archive {
+            project.archive(&archive)
+         } else {
+            project.create()
+         }
+      }
+      Commands::Init { project, force } => {
+         env::current_dir()
+            .map_err(|source| FluxorCliError::io(".", source))
+            .and_then(|directory| project.init_project(force).init(&directory))
+      }
+      Commands::Add { command } => {
+         env::current_dir()
+            .map_err(|source| FluxorCliError::io(".", source))
+            .and_then(|directory| fluxor_cli::add::find_project_root(&directory))
+            .and_then(|root| match command {
+               AddCommands::Route { method, path, kind, handler } => {
+                  AddRoute { method: &method, path: &path, kind, handler: handler.as_deref() }.add(&root)
+               }
+               AddCommands::Page { name, path, title } => {
+                  AddPage { name: &name, path: path.as_deref(), title: title.as_deref() }.add(&root)
+               }
+               AddCommands::Component { name, params } => AddComponent { name: &name, params: &params }.add(&root),
+            })
+      }
+      Commands::Routes { format } => {
+         env::current_dir()
+            .map_err(|source| FluxorCliError::io(".", source))
+            .and_then(|directory| fluxor_cli::add::find_project_root(&directory))
+            .and_then(|root| RouteTable::load(&root))
+            .map(|table| print!("{}", table.render(format)))
+      }
+      Commands::Check { command } => {
+         env::current_dir()
+            .map_err(|source| FluxorCliError::io(".", source))
+            .and_then(|directory| fluxor_cli::add::find_project_root(&directory))
+            .and_then(|root| match command {
+               CheckCommands::Routes => fluxor_cli::check::check_project_routes(&root),
+            })
       }
+      Commands::OpenApi { format, output } => {
+         env::current_dir()
+            .map_err(|source| FluxorCliError::io(".", source))
+            .and_then(|directory| fluxor_cli::add::find_project_root(&directory))
+            .and_then(|root| OpenApi::load(&root))
+            .and_then(|document| match output {
+               Some(output) => document.write(&output, format),
+               None => {
+                  print!("{}", document.render(format));
+                  Ok(())
+               }
+            })
+      }
+      Commands::Dev { bin } => {
+         env::current_dir()
+            .map_err(|source| FluxorCliError::io(".", source))
+            .and_then(|directory| fluxor_cli::add::find_project_root(&directory))
+            .and_then(|root| DevServer { bin: bin.as_deref() }.run(&root))
+      }
+      Commands::Doctor => {
+         env::current_dir()
+            .map_err(|source| FluxorCliError::io(".", source))
+            .and_then(|directory| fluxor_cli::doctor::run_doctor(&directory))
+      }
+      Commands::Upgrade { to, major, workspace, offline } => {
+         env::current_dir()
+            .map_err(|source| FluxorCliError::io(".", source))
+            .and_then(|directory| Upgrade { to: &to, major, workspace, offline }.upgrade(&directory))
+      }
+      Commands::Templates { command } => match command {
+         TemplatesCommands::Update => fluxor_cli::template::git::update_cached(),
+      },
+      Commands::List { markdown } => {
+         if markdown {
+            print!("{}", examples_markdown());
+         } else {
+            print!("{}", examples_listing());
+         }
+         Ok(())
+      }
+   };
+
+   if let Err(error) = result {
+      eprintln!("Error: {}", error);
+      process::exit(error.exit_code());
    }
-}
\ No newline at end of file
+}
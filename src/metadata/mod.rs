@@ -1,27 +1,25 @@
-use std::fs;
 use std::path::Path;
 
-pub fn create_gitignore(path: &Path, content: &'static str) {
-    fs::write(path.join(".gitignore"), content)
-        .expect("Failed to create .gitignore");
+use crate::error::Result;
+use crate::sink::ProjectSink;
+
+pub fn create_gitignore(sink: &mut dyn ProjectSink, path: &Path, content: &'static str) -> Result<()> {
+    sink.write_file(&path.join(".gitignore"), content.as_bytes())
 }
 
-pub fn create_readme(path: &Path, package_name: &str) {
+pub fn create_readme(sink: &mut dyn ProjectSink, path: &Path, package_name: &str) -> Result<()> {
     let content = format!(r#"# {}
 
 This project has been initialized with the assistance of the [Fluxor CLI](https://crates.io/crates/fluxor_cli), a command-line tool that allows developers to quickly and efficiently create project starters for the [Fluxor web framework](https://crates.io/crates/fluxor)."
 "#, package_name);
 
-    fs::write(path.join("README.md"), content)
-        .expect("Failed to create README.md");
+    sink.write_file(&path.join("README.md"), content.as_bytes())
 }
 
-pub fn create_env(path: &Path, content: &'static str) {
-    fs::write(path.join(".env"), content)
-        .expect("Failed to create .env");
+pub fn create_env(sink: &mut dyn ProjectSink, path: &Path, content: &'static str) -> Result<()> {
+    sink.write_file(&path.join(".env"), content.as_bytes())
 }
 
-pub fn create_license(path: &Path, file_name: &str, content: &'static str) {
-    fs::write(path.join(file_name), content)
-        .expect(&format!("Failed to create {}", file_name));
+pub fn create_license(sink: &mut dyn ProjectSink, path: &Path, file_name: &str, content: &'static str) -> Result<()> {
+    sink.write_file(&path.join(file_name), content.as_bytes())
 }
\ No newline at end of file
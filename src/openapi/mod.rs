@@ -0,0 +1,541 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+use serde_json::{Map, Value, json};
+
+use crate::check::{RouteIssue, unreachable};
+use crate::error::{FluxorCliError, Result};
+use crate::routes::{Route, RouteTable, package_field};
+
+/// The OpenAPI version of the generated documents.
+pub const OPENAPI_VERSION: &str = "3.1.0";
+
+/// The output of `fluxor openapi`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OpenApiFormat {
+    Yaml,
+    Json,
+}
+
+/// An OpenAPI document built from the routes of a project.
+///
+/// Every `<param>` segment becomes a required string path parameter. Handlers can describe
+/// their responses with doc-comment tags, one per line:
+///
+/// ```text
+/// /// Returns the greeting.
+/// /// @response 200 application/json The greeting message
+/// /// @response 404 Unknown name
+/// pub fn hello_world(_req: Req, _params: Params) -> Reply { ... }
+/// ```
+///
+/// The first untagged line becomes the operation summary, the other untagged lines its description.
+///
+/// Routes that Fluxor never calls, because an earlier route matches all their requests, are left
+/// out. Paths that only differ in parameter names, e.g. `/users/<id>` and `/users/<name>`, share
+/// one path item named after the first registered route.
+#[derive(Debug, Clone)]
+pub struct OpenApi {
+    document: Value,
+    skipped: Vec<RouteIssue>,
+}
+
+impl OpenApi {
+    /// Builds the document for the project at `root`, titled after its package.
+    pub fn load(root: &Path) -> Result<Self> {
+        let table = RouteTable::load(root)?;
+        let title = package_field(root, "name").unwrap_or_else(|| "fluxor".to_string());
+        let version = package_field(root, "version").unwrap_or_else(|| "0.1.0".to_string());
+        let description = package_field(root, "description");
+
+        let openapi = Self::from_routes(&table, &title, &version, description.as_deref());
+        for issue in &openapi.skipped {
+            eprintln!("{} (left out of the document)", issue);
+        }
+
+        Ok(openapi)
+    }
+
+    /// Builds the document for `table`.
+    pub fn from_routes(table: &RouteTable, title: &str, version: &str, description: Option<&str>) -> Self {
+        let mut info = Map::new();
+        info.insert("title".to_string(), json!(title));
+        info.insert("version".to_string(), json!(version));
+        if let Some(description) = description {
+            info.insert("description".to_string(), json!(description));
+        }
+
+        let mut paths = Map::new();
+        let mut templates = BTreeMap::new();
+        let mut operation_ids = BTreeSet::new();
+        let mut skipped = Vec::new();
+
+        for (index, route) in table.routes.iter().enumerate() {
+            if let Some(issue) = unreachable(&table.routes, index) {
+                skipped.push(issue);
+                continue;
+            }
+
+            let template = templates
+                .entry(path_shape(&route.path))
+                .or_insert_with(|| openapi_path(&route.path))
+                .clone();
+            let operation = operation(route, &template, &mut operation_ids);
+            paths
+                .entry(template)
+                .or_insert_with(|| Value::Object(Map::new()))
+                .as_object_mut()
+                .expect("path items are objects")
+                .insert(route.method.to_lowercase(), operation);
+        }
+
+        OpenApi {
+            document: json!({
+                "openapi": OPENAPI_VERSION,
+                "info": info,
+                "paths": paths,
+            }),
+            skipped,
+        }
+    }
+
+    /// The routes left out of the document because an earlier route handles their requests.
+    pub fn skipped(&self) -> &[RouteIssue] {
+        &self.skipped
+    }
+
+    /// The number of paths in the document.
+    pub fn path_count(&self) -> usize {
+        self.document["paths"].as_object().map_or(0, Map::len)
+    }
+
+    /// Writes the document to `path` in `format`.
+    pub fn write(&self, path: &Path, format: OpenApiFormat) -> Result<()> {
+        fs::write(path, self.render(format)).map_err(|source| FluxorCliError::io(path, source))?;
+        println!("Wrote the OpenAPI document ({} paths) to '{}'.", self.path_count(), path.display());
+        Ok(())
+    }
+
+    /// Renders the document in `format`.
+    pub fn render(&self, format: OpenApiFormat) -> String {
+        match format {
+            OpenApiFormat::Yaml => to_yaml(&self.document),
+            OpenApiFormat::Json => serde_json::to_string_pretty(&self.document).unwrap_or_default() + "\n",
+        }
+    }
+}
+
+/// `/api/users/<id>` becomes `/api/users/{id}`.
+///
+/// ```
+/// assert_eq!(fluxor_cli::openapi::openapi_path("/api/users/<id>"), "/api/users/{id}");
+/// ```
+pub fn openapi_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| match segment.strip_prefix('<').and_then(|segment| segment.strip_suffix('>')) {
+            Some(name) => format!("{{{}}}", name),
+            None => segment.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// `path` with every parameter name removed, e.g. `/users/<>` for `/users/<id>`.
+fn path_shape(path: &str) -> String {
+    path.split('/')
+        .map(|segment| if segment.starts_with('<') && segment.ends_with('>') { "<>" } else { segment })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// The operation of `route`, whose parameters are named after the `{param}` segments of `path`.
+fn operation(route: &Route, path: &str, operation_ids: &mut BTreeSet<String>) -> Value {
+    let mut operation = Map::new();
+
+    let name = route.handler.rsplit("::").next().unwrap_or(&route.handler).to_string();
+    let mut operation_id = name.clone();
+    let mut suffix = 2;
+    while !operation_ids.insert(operation_id.clone()) {
+        operation_id = format!("{}_{}", name, suffix);
+        suffix += 1;
+    }
+    operation.insert("operationId".to_string(), json!(operation_id));
+
+    let docs = route.function.as_ref().map(|function| function.docs.as_slice()).unwrap_or_default();
+    let text: Vec<&str> = docs
+        .iter()
+        .map(|line| line.trim())
+        .filter(|line| !line.starts_with('@'))
+        .collect();
+    let mut paragraphs = text.split(|line| line.is_empty()).filter(|lines| !lines.is_empty());
+    if let Some(summary) = paragraphs.next() {
+        operation.insert("summary".to_string(), json!(summary.join(" ")));
+    }
+    let description: Vec<String> = paragraphs.map(|lines| lines.join(" ")).collect();
+    if !description.is_empty() {
+        operation.insert("description".to_string(), json!(description.join("\n\n")));
+    }
+
+    let parameters: Vec<Value> = path
+        .split('/')
+        .filter_map(|segment| segment.strip_prefix('{').and_then(|segment| segment.strip_suffix('}')))
+        .map(|name| {
+            json!({
+                "name": name,
+                "in": "path",
+                "required": true,
+                "schema": { "type": "string" },
+            })
+        })
+        .collect();
+    if !parameters.is_empty() {
+        operation.insert("parameters".to_string(), Value::Array(parameters));
+    }
+
+    let mut responses = Map::new();
+    for tag in docs.iter().filter_map(|line| line.trim().strip_prefix("@response")) {
+        let mut words = tag.split_whitespace().peekable();
+        let Some(status) = words.next() else {
+            continue;
+        };
+        let media_type = words.next_if(|word| word.contains('/'));
+        let description = words.collect::<Vec<_>>().join(" ");
+
+        let response = responses
+            .entry(status.to_string())
+            .or_insert_with(|| json!({ "description": "" }))
+            .as_object_mut()
+            .expect("responses are objects");
+        if !description.is_empty() {
+            response.insert("description".to_string(), json!(description));
+        }
+        if let Some(media_type) = media_type {
+            let content = response.entry("content").or_insert_with(|| Value::Object(Map::new()));
+            content[media_type] = json!({});
+        }
+    }
+    for (status, response) in responses.iter_mut() {
+        if response["description"] == "" {
+            response["description"] = json!(reason_phrase(status));
+        }
+    }
+    if responses.is_empty() {
+        responses.insert("200".to_string(), json!({ "description": "Successful response" }));
+    }
+    operation.insert("responses".to_string(), Value::Object(responses));
+
+    Value::Object(operation)
+}
+
+/// The description of a `@response` tag without one.
+fn reason_phrase(status: &str) -> &'static str {
+    match status {
+        "200" => "OK",
+        "201" => "Created",
+        "202" => "Accepted",
+        "204" => "No Content",
+        "301" => "Moved Permanently",
+        "302" => "Found",
+        "304" => "Not Modified",
+        "400" => "Bad Request",
+        "401" => "Unauthorized",
+        "403" => "Forbidden",
+        "404" => "Not Found",
+        "409" => "Conflict",
+        "422" => "Unprocessable Content",
+        "500" => "Internal Server Error",
+        "503" => "Service Unavailable",
+        _ => "Response",
+    }
+}
+
+/// Emits `value` as block-style YAML.
+///
+/// Only the subset of YAML needed for OpenAPI documents: strings that could be read as another
+/// type, or that contain special characters, are written as double-quoted JSON strings.
+fn to_yaml(value: &Value) -> String {
+    let mut output = String::new();
+    write_yaml(&mut output, value, 0);
+    output
+}
+
+fn write_yaml(output: &mut String, value: &Value, indent: usize) {
+    let pad = " ".repeat(indent);
+
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                output.push_str(&format!("{}{}:", pad, yaml_scalar(&Value::String(key.clone()))));
+                write_yaml_child(output, value, indent);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                // Write the item indented, then replace the indent of its first line with the dash
+                let mut entry = String::new();
+                match item {
+                    Value::Object(map) if !map.is_empty() => write_yaml(&mut entry, item, indent + 2),
+                    Value::Array(items) if !items.is_empty() => write_yaml(&mut entry, item, indent + 2),
+                    _ => entry.push_str(&format!("{}  {}\n", pad, yaml_scalar(item))),
+                }
+                output.push_str(&pad);
+                output.push_str("- ");
+                output.push_str(&entry[indent + 2..]);
+            }
+        }
+        _ => {
+            output.push_str(&pad);
+            output.push_str(&yaml_scalar(value));
+            output.push('\n');
+        }
+    }
+}
+
+fn write_yaml_child(output: &mut String, value: &Value, indent: usize) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            output.push('\n');
+            write_yaml(output, value, indent + 2);
+        }
+        Value::Array(items) if !items.is_empty() => {
+            output.push('\n');
+            write_yaml(output, value, indent + 2);
+        }
+        _ => {
+            output.push(' ');
+            output.push_str(&yaml_scalar(value));
+            output.push('\n');
+        }
+    }
+}
+
+fn yaml_scalar(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Bool(value) => value.to_string(),
+        Value::Number(value) => value.to_string(),
+        Value::Object(_) => "{}".to_string(),
+        Value::Array(_) => "[]".to_string(),
+        Value::String(text) => {
+            // Numbers in any notation (`1e3`, `0x1F`, `.inf`, `+1`) start with a digit, `+` or `.`
+            let plain = !text.is_empty()
+                && text.trim() == text
+                && !text.starts_with(|c: char| c.is_ascii_digit() || "+.-?:,[]{}#&*!|>'\"%@`~".contains(c))
+                && !text.ends_with(':')
+                && !text.contains(": ")
+                && !text.contains(" #")
+                && !text.contains(|c: char| c.is_control())
+                && !matches!(
+                    text.to_lowercase().as_str(),
+                    "true" | "false" | "yes" | "no" | "y" | "n" | "on" | "off" | "null" | "inf" | "nan"
+                );
+
+            if plain { text.clone() } else { yaml_quoted(text) }
+        }
+    }
+}
+
+/// `text` as a double-quoted YAML string, escaping quotes, backslashes and control characters.
+fn yaml_quoted(text: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    const MAIN: &str = r#"use fluxor::prelude::*;
+
+fn main() {
+    let mut app = Fluxor::new();
+    app.route(GET, "/", home);
+    app.route(GET, "/users/<id>", user);
+    app.route(DELETE, "/users/<name>", delete_user);
+    app.route(GET, "/users/<name>", user);
+    app.route(GET, "/users/me", user);
+    app.route(POST, "/api/msg", api_message);
+    app.route(POST, "/api/msg", api_message);
+}
+
+/// Serves the home page.
+fn home(_req: Req, _params: Params) -> Reply {
+    todo!()
+}
+
+/// Returns a user: id, name
+///
+/// Looks the user up by `<id>`.
+/// @response 200 application/json The user
+/// @response 404
+fn user(_req: Req, _params: Params) -> Reply {
+    todo!()
+}
+
+fn delete_user(_req: Req, _params: Params) -> Reply {
+    todo!()
+}
+
+fn api_message(_req: Req, _params: Params) -> Reply {
+    todo!()
+}
+"#;
+
+    fn openapi(main: &str) -> OpenApi {
+        let table = RouteTable::from_sources(&[(PathBuf::from("src/main.rs"), main.to_string())], Some("app")).unwrap();
+        OpenApi::from_routes(&table, "app", "0.1.0", Some("Demo: the #1 app"))
+    }
+
+    #[test]
+    fn documents_match_the_golden_yaml() {
+        assert_eq!(
+            openapi(MAIN).render(OpenApiFormat::Yaml),
+            r#"openapi: "3.1.0"
+info:
+  title: app
+  version: "0.1.0"
+  description: "Demo: the #1 app"
+paths:
+  /:
+    get:
+      operationId: home
+      summary: Serves the home page.
+      responses:
+        "200":
+          description: Successful response
+  /users/{id}:
+    get:
+      operationId: user
+      summary: "Returns a user: id, name"
+      description: Looks the user up by `<id>`.
+      parameters:
+        - name: id
+          in: path
+          required: true
+          schema:
+            type: string
+      responses:
+        "200":
+          description: The user
+          content:
+            application/json: {}
+        "404":
+          description: Not Found
+    delete:
+      operationId: delete_user
+      parameters:
+        - name: id
+          in: path
+          required: true
+          schema:
+            type: string
+      responses:
+        "200":
+          description: Successful response
+  /api/msg:
+    post:
+      operationId: api_message
+      responses:
+        "200":
+          description: Successful response
+"#
+        );
+    }
+
+    #[test]
+    fn unreachable_routes_are_left_out() {
+        let openapi = openapi(MAIN);
+        let skipped: Vec<String> = openapi
+            .skipped()
+            .iter()
+            .map(|issue| match issue {
+                RouteIssue::Duplicate { route, .. } | RouteIssue::Shadowed { route, .. } => format!("{} {}", route.method, route.path),
+                issue => issue.to_string(),
+            })
+            .collect();
+
+        assert_eq!(skipped, ["GET /users/<name>", "GET /users/me", "POST /api/msg"]);
+        assert_eq!(openapi.path_count(), 3);
+    }
+
+    #[test]
+    fn json_matches_the_yaml_document() {
+        let openapi = openapi(MAIN);
+        let json: Value = serde_json::from_str(&openapi.render(OpenApiFormat::Json)).unwrap();
+
+        assert_eq!(json, openapi.document);
+        assert_eq!(json["paths"]["/users/{id}"]["delete"]["parameters"][0]["name"], "id");
+    }
+
+    #[test]
+    fn strings_that_yaml_would_misread_are_quoted() {
+        let scalar = |text: &str| yaml_scalar(&json!(text));
+
+        for plain in ["hello", "Returns the user", "/users/{id}", "application/json", "a:b", "C# notes", "über"] {
+            assert_eq!(scalar(plain), plain);
+        }
+        for (text, quoted) in [
+            ("", r#""""#),
+            ("true", r#""true""#),
+            ("No", r#""No""#),
+            ("y", r#""y""#),
+            ("null", r#""null""#),
+            ("~", r#""~""#),
+            ("200", r#""200""#),
+            ("1.0", r#""1.0""#),
+            ("1e3", r#""1e3""#),
+            ("0x1F", r#""0x1F""#),
+            ("+1", r#""+1""#),
+            (".inf", r#"".inf""#),
+            ("nan", r#""nan""#),
+            (" padded", r#"" padded""#),
+            ("- item", r#""- item""#),
+            ("key: value", r#""key: value""#),
+            ("ends with:", r#""ends with:""#),
+            ("text # comment", r#""text # comment""#),
+            ("*alias", r#""*alias""#),
+            ("'single'", r#""'single'""#),
+        ] {
+            assert_eq!(scalar(text), quoted, "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn quoted_strings_are_escaped() {
+        let scalar = |text: &str| yaml_scalar(&json!(text));
+
+        assert_eq!(scalar(r#""quoted" \ path"#), r#""\"quoted\" \\ path""#);
+        assert_eq!(scalar("two\nlines\tand tab"), r#""two\nlines\tand tab""#);
+        assert_eq!(scalar("bell\u{7}del\u{7f}"), r#""bell\u0007del\u007f""#);
+    }
+
+    #[test]
+    fn nested_arrays_and_empty_values_are_written_inline_or_indented() {
+        let value = json!({
+            "tags": ["a", "true"],
+            "empty": {},
+            "none": [],
+            "nested": [[1, 2], { "key": null }],
+        });
+
+        assert_eq!(
+            to_yaml(&value),
+            "tags:\n  - a\n  - \"true\"\nempty: {}\nnone: []\nnested:\n  - - 1\n    - 2\n  - key: null\n"
+        );
+    }
+}
@@ -0,0 +1,784 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use proc_macro2::Span;
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{Expr, ExprCall, ExprMethodCall, ItemFn, ItemMod, ItemUse, Lit, UseTree};
+use toml_edit::DocumentMut;
+
+use crate::error::{FluxorCliError, Result};
+
+/// Route registrations are method calls on a receiver with one of these names.
+pub const ROUTE_RECEIVERS: &[&str] = &["app", "server"];
+
+/// The output of `fluxor routes`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum RouteFormat {
+    Table,
+    Json,
+}
+
+/// The handlers exported by `fluxor::prelude`.
+pub const PRELUDE_HANDLERS: &[&str] = &["serve_http_client"];
+
+/// A place in the project's sources.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Location {
+    /// The file, relative to the project root.
+    pub file: PathBuf,
+    pub line: usize,
+}
+
+impl std::fmt::Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.file.display(), self.line)
+    }
+}
+
+/// A function defined at module level in the project.
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The absolute path, e.g. `crate::routes::pages::home_page`.
+    pub path: String,
+    pub location: Location,
+    /// Whether the function has the `(Req, Params) -> Reply` signature of a Fluxor handler.
+    pub is_handler: bool,
+    /// The lines of the function's `///` doc comment, without the leading space.
+    pub docs: Vec<String>,
+}
+
+/// A `route(METHOD, "path", handler)` registration.
+#[derive(Debug, Clone)]
+pub struct Route {
+    /// The HTTP method, e.g. `GET`.
+    pub method: String,
+    /// The route path, e.g. `/api/users/<id>`.
+    pub path: String,
+    /// The handler as written at the call site, e.g. `api::msg::api_message`.
+    pub handler: String,
+    /// The function the handler resolves to, if it is defined in the project.
+    pub function: Option<Function>,
+    /// Whether the handler may come from another crate, e.g. `serve_http_client` from `fluxor::prelude::*`.
+    pub external: bool,
+    /// Where the route is registered.
+    pub location: Location,
+}
+
+/// The routes and functions found in a project's `src` directory.
+#[derive(Debug, Default)]
+pub struct RouteTable {
+    pub routes: Vec<Route>,
+    pub functions: Vec<Function>,
+}
+
+impl RouteTable {
+    /// Parses every Rust file under `root/src` and collects the route registrations.
+    ///
+    /// Handlers are resolved through `use` declarations, `pub use` re-exports and glob imports
+    /// to the function that defines them; handlers from other crates (e.g. `serve_http_client`
+    /// from `fluxor::prelude`) are left unresolved.
+    pub fn load(root: &Path) -> Result<Self> {
+        let mut files = Vec::new();
+        collect_rust_files(&root.join("src"), &mut files)?;
+        files.sort();
+
+        let mut sources = Vec::new();
+        for file in files {
+            let source = fs::read_to_string(&file).map_err(|source| FluxorCliError::io(&file, source))?;
+            sources.push((file.strip_prefix(root).unwrap_or(&file).to_path_buf(), source));
+        }
+
+        Self::from_sources(&sources, package_name(root).as_deref())
+    }
+
+    /// Collects the routes of `sources`, given as paths relative to the project root such as
+    /// `src/routes/mod.rs` and their contents, of the crate named `crate_name`.
+    ///
+    /// The routes are listed in the order Fluxor registers them: the calls of `main` are followed
+    /// into the project functions they call, such as `setup_routes`. Routes in functions that are
+    /// not reached this way come last, in the order of their files.
+    pub(crate) fn from_sources(sources: &[(PathBuf, String)], crate_name: Option<&str>) -> Result<Self> {
+        let index = ModuleIndex::build(sources)?;
+
+        let routes = index
+            .registration_order(crate_name)
+            .into_iter()
+            .map(|call| {
+                let call = &index.calls[call];
+                let function = index
+                    .resolve(&call.module, &call.handler, crate_name)
+                    .and_then(|path| index.functions.get(&path).cloned());
+                let external = function.is_none() && index.is_external(&call.module, &call.handler, crate_name, 0);
+
+                Route {
+                    method: call.method.clone(),
+                    path: call.path.clone(),
+                    handler: call.handler.join("::"),
+                    function,
+                    external,
+                    location: call.location.clone(),
+                }
+            })
+            .collect();
+
+        Ok(RouteTable {
+            routes,
+            functions: index.functions.into_values().collect(),
+        })
+    }
+
+    /// Renders the routes in `format`.
+    pub fn render(&self, format: RouteFormat) -> String {
+        match format {
+            RouteFormat::Table => self.to_table(),
+            RouteFormat::Json => self.to_json() + "\n",
+        }
+    }
+
+    /// Renders the routes as an aligned text table.
+    pub fn to_table(&self) -> String {
+        let header = ["METHOD", "PATH", "HANDLER", "LOCATION"];
+        let rows: Vec<[String; 4]> = self
+            .routes
+            .iter()
+            .map(|route| {
+                let handler = match &route.function {
+                    Some(function) => function.path.clone(),
+                    None => route.handler.clone(),
+                };
+                [route.method.clone(), route.path.clone(), handler, route.location.to_string()]
+            })
+            .collect();
+
+        let mut widths = header.map(str::len);
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let mut output = String::new();
+        let mut push_row = |cells: [&str; 4]| {
+            let line = cells
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join("  ");
+            output.push_str(line.trim_end());
+            output.push('\n');
+        };
+
+        push_row(header);
+        for row in &rows {
+            push_row([&row[0], &row[1], &row[2], &row[3]]);
+        }
+
+        output
+    }
+
+    /// Renders the routes as a JSON array for tooling.
+    pub fn to_json(&self) -> String {
+        let routes: Vec<serde_json::Value> = self
+            .routes
+            .iter()
+            .map(|route| {
+                serde_json::json!({
+                    "method": route.method,
+                    "path": route.path,
+                    "handler": route.handler,
+                    "resolved": route.function.as_ref().map(|function| function.path.clone()),
+                    "definition": route.function.as_ref().map(|function| location_json(&function.location)),
+                    "location": location_json(&route.location),
+                })
+            })
+            .collect();
+
+        serde_json::to_string_pretty(&routes).unwrap_or_default()
+    }
+}
+
+fn location_json(location: &Location) -> serde_json::Value {
+    serde_json::json!({
+        "file": location.file.display().to_string(),
+        "line": location.line,
+    })
+}
+
+/// A route registration before its handler is resolved.
+#[derive(Debug)]
+struct RouteCall {
+    method: String,
+    path: String,
+    handler: Vec<String>,
+    module: Vec<String>,
+    location: Location,
+}
+
+/// A route registration or a function call in the body of a function, in source order.
+#[derive(Debug)]
+enum BodyEvent {
+    /// The index of a route call in [`ModuleIndex::calls`].
+    Route(usize),
+    /// A call of the function at `path`, as written in `module`.
+    Call { module: Vec<String>, path: Vec<String> },
+}
+
+/// Names visible in every module, keyed by absolute module path.
+#[derive(Debug, Default)]
+struct ModuleIndex {
+    modules: BTreeSet<Vec<String>>,
+    /// `use` aliases: module -> (name -> path as written).
+    uses: BTreeMap<Vec<String>, BTreeMap<String, Vec<String>>>,
+    /// `use path::*` imports: module -> paths as written.
+    globs: BTreeMap<Vec<String>, Vec<Vec<String>>>,
+    /// Functions keyed by absolute path (`crate::a::b`).
+    functions: BTreeMap<String, Function>,
+    /// The route registrations and calls in the body of each function, keyed by absolute path.
+    bodies: BTreeMap<String, Vec<BodyEvent>>,
+    calls: Vec<RouteCall>,
+}
+
+impl ModuleIndex {
+    /// Indexes `sources`, given as paths relative to the project root and their contents.
+    fn build(sources: &[(PathBuf, String)]) -> Result<Self> {
+        let mut index = ModuleIndex::default();
+
+        for (file, source) in sources {
+            let syntax = syn::parse_file(source).map_err(|e| FluxorCliError::SourceParse {
+                path: file.clone(),
+                message: format!("line {}: {}", e.span().start().line, e),
+            })?;
+
+            let mut collector = Collector {
+                index: &mut index,
+                file: file.clone(),
+                module: module_path(file),
+                fn_depth: 0,
+                function: None,
+            };
+            for depth in 1..=collector.module.len() {
+                collector.index.modules.insert(collector.module[..depth].to_vec());
+            }
+            collector.visit_file(&syntax);
+        }
+
+        Ok(index)
+    }
+
+    /// The indices of [`ModuleIndex::calls`] in registration order: the route calls reached from
+    /// `main` first, then the others in source order.
+    fn registration_order(&self, crate_name: Option<&str>) -> Vec<usize> {
+        let mut order = Vec::new();
+        let mut visited = BTreeSet::new();
+        self.follow(&absolute(&[], "main"), crate_name, &mut visited, &mut order);
+
+        for call in 0..self.calls.len() {
+            if !order.contains(&call) {
+                order.push(call);
+            }
+        }
+
+        order
+    }
+
+    /// Appends the route calls of the function at `path`, and of the functions it calls, to `order`.
+    fn follow(&self, path: &str, crate_name: Option<&str>, visited: &mut BTreeSet<String>, order: &mut Vec<usize>) {
+        if !visited.insert(path.to_string()) {
+            return;
+        }
+
+        for event in self.bodies.get(path).into_iter().flatten() {
+            match event {
+                BodyEvent::Route(call) => {
+                    if !order.contains(call) {
+                        order.push(*call);
+                    }
+                }
+                BodyEvent::Call { module, path } => {
+                    if let Some(function) = self.resolve(module, path, crate_name) {
+                        self.follow(&function, crate_name, visited, order);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Resolves `path` as written in `module` to the absolute path of a project function.
+    fn resolve(&self, module: &[String], path: &[String], crate_name: Option<&str>) -> Option<String> {
+        let (name, prefix) = path.split_last()?;
+        let module = if prefix.is_empty() {
+            module.to_vec()
+        } else {
+            self.resolve_module(module, prefix, crate_name, 0)?
+        };
+
+        self.lookup(&module, name, crate_name, 0)
+    }
+
+    /// Finds the function `name` defined in, imported into or re-exported from `module`.
+    fn lookup(&self, module: &[String], name: &str, crate_name: Option<&str>, depth: usize) -> Option<String> {
+        // Guard against `use` cycles
+        if depth > 16 {
+            return None;
+        }
+
+        let path = absolute(module, name);
+        if self.functions.contains_key(&path) {
+            return Some(path);
+        }
+
+        if let Some((last, prefix)) = self.uses.get(module).and_then(|uses| uses.get(name)).and_then(|target| target.split_last()) {
+            let target = if prefix.is_empty() {
+                module.to_vec()
+            } else {
+                self.resolve_module(module, prefix, crate_name, depth + 1)?
+            };
+            return self.lookup(&target, last, crate_name, depth + 1);
+        }
+
+        // Glob imports such as `use crate::components::*;`
+        self.globs.get(module)?.iter().find_map(|glob| {
+            let target = self.resolve_module(module, glob, crate_name, depth + 1)?;
+            self.lookup(&target, name, crate_name, depth + 1)
+        })
+    }
+
+    /// Whether `path` as written in `module` may name an item of another crate.
+    fn is_external(&self, module: &[String], path: &[String], crate_name: Option<&str>, depth: usize) -> bool {
+        let Some(first) = path.first() else {
+            return false;
+        };
+        if depth > 16
+            || ["crate", "self", "super"].contains(&first.as_str())
+            || Some(first.as_str()) == crate_name
+            || self.modules.contains(&child(module, first))
+        {
+            return false;
+        }
+
+        if let Some(target) = self.uses.get(module).and_then(|uses| uses.get(first)) {
+            return self.is_external(module, target, crate_name, depth + 1);
+        }
+
+        if path.len() > 1 {
+            // An unknown first segment names another crate
+            return true;
+        }
+
+        // A single name may come from a glob import of another crate, e.g. `use fluxor::prelude::*;`
+        self.globs.get(module).is_some_and(|globs| {
+            globs.iter().any(|glob| {
+                if glob == &["fluxor", "prelude"] {
+                    PRELUDE_HANDLERS.contains(&first.as_str())
+                } else {
+                    self.resolve_module(module, glob, crate_name, depth + 1).is_none()
+                }
+            })
+        })
+    }
+
+    /// Resolves a module path as written in `module`; paths into other crates resolve to `None`.
+    fn resolve_module(&self, module: &[String], path: &[String], crate_name: Option<&str>, depth: usize) -> Option<Vec<String>> {
+        if depth > 16 {
+            return None;
+        }
+
+        let (first, rest) = path.split_first()?;
+        let mut current = if first == "crate" || Some(first.as_str()) == crate_name {
+            Vec::new()
+        } else if first == "self" {
+            module.to_vec()
+        } else if first == "super" {
+            module[..module.len().checked_sub(1)?].to_vec()
+        } else if self.modules.contains(&child(module, first)) {
+            child(module, first)
+        } else {
+            let target = self.uses.get(module)?.get(first)?;
+            self.resolve_module(module, target, crate_name, depth + 1)?
+        };
+
+        for segment in rest {
+            current = if segment == "super" {
+                current[..current.len().checked_sub(1)?].to_vec()
+            } else if self.modules.contains(&child(&current, segment)) {
+                child(&current, segment)
+            } else {
+                let target = self.uses.get(&current)?.get(segment)?;
+                self.resolve_module(&current, target, crate_name, depth + 1)?
+            };
+        }
+
+        Some(current)
+    }
+}
+
+fn child(module: &[String], name: &str) -> Vec<String> {
+    let mut path = module.to_vec();
+    path.push(name.to_string());
+    path
+}
+
+fn absolute(module: &[String], name: &str) -> String {
+    let mut path = vec!["crate"];
+    path.extend(module.iter().map(String::as_str));
+    path.push(name);
+    path.join("::")
+}
+
+/// Walks one file, recording modules, `use` declarations, functions and route calls.
+struct Collector<'a> {
+    index: &'a mut ModuleIndex,
+    file: PathBuf,
+    module: Vec<String>,
+    fn_depth: usize,
+    /// The absolute path of the module-level function being walked.
+    function: Option<String>,
+}
+
+impl Collector<'_> {
+    /// Records `event` in the body of the function being walked.
+    fn record(&mut self, event: BodyEvent) {
+        if let Some(function) = &self.function {
+            self.index.bodies.entry(function.clone()).or_default().push(event);
+        }
+    }
+
+    fn location(&self, span: Span) -> Location {
+        Location {
+            file: self.file.clone(),
+            line: span.start().line,
+        }
+    }
+
+    fn record_use(&mut self, prefix: &mut Vec<String>, tree: &UseTree) {
+        match tree {
+            UseTree::Path(path) => {
+                prefix.push(path.ident.to_string());
+                self.record_use(prefix, &path.tree);
+                prefix.pop();
+            }
+            UseTree::Name(name) => {
+                let ident = name.ident.to_string();
+                let mut target = prefix.clone();
+                target.push(ident.clone());
+                let alias = if ident == "self" { prefix.last().cloned().unwrap_or(ident) } else { ident };
+                self.index.uses.entry(self.module.clone()).or_default().insert(alias, target);
+            }
+            UseTree::Rename(rename) => {
+                let mut target = prefix.clone();
+                target.push(rename.ident.to_string());
+                self.index
+                    .uses
+                    .entry(self.module.clone())
+                    .or_default()
+                    .insert(rename.rename.to_string(), target);
+            }
+            UseTree::Glob(_) => {
+                self.index.globs.entry(self.module.clone()).or_default().push(prefix.clone());
+            }
+            UseTree::Group(group) => {
+                for tree in &group.items {
+                    self.record_use(prefix, tree);
+                }
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for Collector<'_> {
+    fn visit_item_mod(&mut self, item: &'ast ItemMod) {
+        let child = child(&self.module, &item.ident.to_string());
+        self.index.modules.insert(child.clone());
+
+        // Inline modules are walked here; `mod name;` files are walked on their own
+        if item.content.is_some() && self.fn_depth == 0 {
+            let parent = std::mem::replace(&mut self.module, child);
+            visit::visit_item_mod(self, item);
+            self.module = parent;
+        }
+    }
+
+    fn visit_item_use(&mut self, item: &'ast ItemUse) {
+        if self.fn_depth == 0 {
+            self.record_use(&mut Vec::new(), &item.tree);
+        }
+    }
+
+    fn visit_item_fn(&mut self, item: &'ast ItemFn) {
+        if self.fn_depth == 0 {
+            let path = absolute(&self.module, &item.sig.ident.to_string());
+            let function = Function {
+                path: path.clone(),
+                location: self.location(item.sig.ident.span()),
+                is_handler: is_handler_signature(item),
+                docs: doc_lines(&item.attrs),
+            };
+            self.index.functions.insert(path.clone(), function);
+            self.function = Some(path);
+        }
+
+        self.fn_depth += 1;
+        visit::visit_item_fn(self, item);
+        self.fn_depth -= 1;
+
+        if self.fn_depth == 0 {
+            self.function = None;
+        }
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        if let Some(route) = self.route_call(call) {
+            self.index.calls.push(route);
+            self.record(BodyEvent::Route(self.index.calls.len() - 1));
+        }
+
+        visit::visit_expr_method_call(self, call);
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast ExprCall) {
+        if let Expr::Path(function) = &*call.func {
+            let path = function.path.segments.iter().map(|segment| segment.ident.to_string()).collect();
+            self.record(BodyEvent::Call {
+                module: self.module.clone(),
+                path,
+            });
+        }
+
+        visit::visit_expr_call(self, call);
+    }
+}
+
+impl Collector<'_> {
+    /// Recognizes `app.route(METHOD, "path", handler)` and `server.route(...)`.
+    fn route_call(&self, call: &ExprMethodCall) -> Option<RouteCall> {
+        if call.method != "route" || call.args.len() != 3 {
+            return None;
+        }
+
+        let Expr::Path(receiver) = &*call.receiver else {
+            return None;
+        };
+        if !ROUTE_RECEIVERS.iter().any(|name| receiver.path.is_ident(name)) {
+            return None;
+        }
+
+        let method = match &call.args[0] {
+            Expr::Path(method) => method.path.segments.last()?.ident.to_string(),
+            _ => return None,
+        };
+        let path = match &call.args[1] {
+            Expr::Lit(literal) => match &literal.lit {
+                Lit::Str(path) => path.value(),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let handler = match &call.args[2] {
+            Expr::Path(handler) => handler.path.segments.iter().map(|segment| segment.ident.to_string()).collect(),
+            other => vec![other.span().source_text().unwrap_or_else(|| "<expression>".to_string())],
+        };
+
+        Some(RouteCall {
+            method,
+            path,
+            handler,
+            module: self.module.clone(),
+            location: self.location(call.method.span()),
+        })
+    }
+}
+
+/// The text of the `#[doc = "..."]` attributes that `///` comments expand to.
+fn doc_lines(attrs: &[syn::Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(meta) => match &meta.value {
+                Expr::Lit(literal) => match &literal.lit {
+                    Lit::Str(text) => Some(text.value()),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        })
+        .flat_map(|text| text.split('\n').map(|line| line.strip_prefix(' ').unwrap_or(line).to_string()).collect::<Vec<_>>())
+        .collect()
+}
+
+/// `fn name(_req: Req, _params: Params) -> Reply`.
+fn is_handler_signature(item: &ItemFn) -> bool {
+    let type_name = |ty: &syn::Type| match ty {
+        syn::Type::Path(path) => path.path.segments.last().map(|segment| segment.ident.to_string()),
+        _ => None,
+    };
+
+    let inputs: Vec<Option<String>> = item
+        .sig
+        .inputs
+        .iter()
+        .map(|input| match input {
+            syn::FnArg::Typed(argument) => type_name(&argument.ty),
+            syn::FnArg::Receiver(_) => None,
+        })
+        .collect();
+    let output = match &item.sig.output {
+        syn::ReturnType::Type(_, ty) => type_name(ty),
+        syn::ReturnType::Default => None,
+    };
+
+    inputs == [Some("Req".to_string()), Some("Params".to_string())] && output.as_deref() == Some("Reply")
+}
+
+/// The module path of a file below `src`; `lib.rs`, `main.rs` and binaries are crate roots.
+fn module_path(file: &Path) -> Vec<String> {
+    let relative = file.strip_prefix("src").unwrap_or(file);
+    let mut segments: Vec<String> = relative
+        .with_extension("")
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().into_owned())
+        .collect();
+
+    if segments.first().map(String::as_str) == Some("bin") {
+        return Vec::new();
+    }
+    if matches!(segments.as_slice(), [name] if name == "lib" || name == "main") {
+        return Vec::new();
+    }
+    if segments.last().map(String::as_str) == Some("mod") {
+        segments.pop();
+    }
+
+    segments
+}
+
+pub(crate) fn collect_rust_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    let entries = fs::read_dir(dir).map_err(|source| FluxorCliError::io(dir, source))?;
+
+    for entry in entries {
+        let path = entry.map_err(|source| FluxorCliError::io(dir, source))?.path();
+        if path.is_dir() {
+            collect_rust_files(&path, files)?;
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+/// The library crate name of the package at `root`, used in paths such as `my_app::routes`.
+fn package_name(root: &Path) -> Option<String> {
+    package_field(root, "name").map(|name| name.replace('-', "_"))
+}
+
+/// A string field of the `[package]` table of the manifest at `root`.
+pub(crate) fn package_field(root: &Path, field: &str) -> Option<String> {
+    let manifest = fs::read_to_string(root.join("Cargo.toml")).ok()?;
+    let document = manifest.parse::<DocumentMut>().ok()?;
+    document.get("package")?.get(field)?.as_str().map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(sources: &[(&str, &str)]) -> ModuleIndex {
+        let sources: Vec<(PathBuf, String)> = sources.iter().map(|(file, source)| (PathBuf::from(file), source.to_string())).collect();
+        ModuleIndex::build(&sources).unwrap()
+    }
+
+    fn path(path: &str) -> Vec<String> {
+        path.split("::").filter(|segment| !segment.is_empty()).map(str::to_string).collect()
+    }
+
+    fn resolve(index: &ModuleIndex, module: &str, handler: &str) -> Option<String> {
+        index.resolve(&path(module), &path(handler), Some("my_app"))
+    }
+
+    #[test]
+    fn use_aliases_and_reexports_resolve_to_the_definition() {
+        let index = index(&[
+            ("src/main.rs", "mod routes;\nuse routes::pages::home_page as index;\nuse crate::routes::pages::about;\n"),
+            ("src/routes/mod.rs", "pub mod pages;\n"),
+            ("src/routes/pages/mod.rs", "mod home;\nmod about;\npub use home::home_page;\npub use self::about::about;\n"),
+            ("src/routes/pages/home.rs", "pub fn home_page() {}\n"),
+            ("src/routes/pages/about.rs", "pub fn about() {}\n"),
+        ]);
+
+        assert_eq!(resolve(&index, "", "index").as_deref(), Some("crate::routes::pages::home::home_page"));
+        assert_eq!(resolve(&index, "", "about").as_deref(), Some("crate::routes::pages::about::about"));
+        assert_eq!(resolve(&index, "", "routes::pages::home_page").as_deref(), Some("crate::routes::pages::home::home_page"));
+        assert_eq!(resolve(&index, "", "routes::pages::contact"), None);
+    }
+
+    #[test]
+    fn super_and_crate_name_paths_resolve() {
+        let index = index(&[
+            ("src/lib.rs", "pub mod routes;\n"),
+            ("src/main.rs", "use my_app::routes::setup_routes;\n"),
+            ("src/routes/mod.rs", "pub mod api;\npub mod shared;\npub fn setup_routes() {}\n"),
+            ("src/routes/shared.rs", "pub fn reply() {}\n"),
+            ("src/routes/api/mod.rs", "use super::shared::reply;\n"),
+        ]);
+
+        assert_eq!(resolve(&index, "", "setup_routes").as_deref(), Some("crate::routes::setup_routes"));
+        assert_eq!(resolve(&index, "routes::api", "reply").as_deref(), Some("crate::routes::shared::reply"));
+        assert_eq!(resolve(&index, "routes::api", "super::shared::reply").as_deref(), Some("crate::routes::shared::reply"));
+        assert_eq!(resolve(&index, "routes::api", "super::super::routes::setup_routes").as_deref(), Some("crate::routes::setup_routes"));
+    }
+
+    #[test]
+    fn inline_modules_and_globs_resolve() {
+        let index = index(&[(
+            "src/main.rs",
+            "use fluxor::prelude::*;\nuse routes::api::*;\n\nmod routes {\n    pub mod api {\n        pub fn hello() {}\n    }\n}\n",
+        )]);
+
+        assert_eq!(resolve(&index, "", "routes::api::hello").as_deref(), Some("crate::routes::api::hello"));
+        assert_eq!(resolve(&index, "", "hello").as_deref(), Some("crate::routes::api::hello"));
+        assert_eq!(resolve(&index, "", "serve_http_client"), None);
+        assert!(index.is_external(&[], &path("serve_http_client"), Some("my_app"), 0));
+        assert!(!index.is_external(&[], &path("missing"), Some("my_app"), 0));
+        assert!(index.is_external(&[], &path("other_crate::handler"), Some("my_app"), 0));
+    }
+
+    #[test]
+    fn use_cycles_do_not_resolve() {
+        let index = index(&[(
+            "src/main.rs",
+            "mod a {\n    pub use super::b::f;\n}\nmod b {\n    pub use super::a::f;\n}\n",
+        )]);
+
+        assert_eq!(resolve(&index, "", "a::f"), None);
+    }
+
+    #[test]
+    fn routes_are_listed_in_registration_order() {
+        let sources = [
+            (
+                "src/a_helpers.rs",
+                "pub fn unused_routes(app: &mut Fluxor) {\n    app.route(GET, \"/orphan\", crate::main_page);\n}\n",
+            ),
+            (
+                "src/main.rs",
+                "mod a_helpers;\nmod routes;\nuse routes::setup_routes;\n\nfn main() {\n    let mut app = Fluxor::new();\n    app.route(GET, \"/first\", main_page);\n    setup_routes(&mut app);\n    app.route(GET, \"/last\", main_page);\n}\n\nfn main_page() {}\n",
+            ),
+            (
+                "src/routes/mod.rs",
+                "pub fn setup_routes(app: &mut Fluxor) {\n    app.route(GET, \"/one\", crate::main_page);\n    more(app);\n}\n\nfn more(app: &mut Fluxor) {\n    app.route(GET, \"/two\", crate::main_page);\n}\n",
+            ),
+        ];
+        let sources: Vec<(PathBuf, String)> = sources.iter().map(|(file, source)| (PathBuf::from(file), source.to_string())).collect();
+
+        let table = RouteTable::from_sources(&sources, Some("my_app")).unwrap();
+        let paths: Vec<&str> = table.routes.iter().map(|route| route.path.as_str()).collect();
+
+        assert_eq!(paths, ["/first", "/one", "/two", "/last", "/orphan"]);
+        assert!(table.routes.iter().all(|route| route.function.is_some()));
+    }
+}
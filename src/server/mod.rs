@@ -0,0 +1,204 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use syn::visit::{self, Visit};
+use syn::{Expr, ExprCall, ExprMethodCall, Lit, Local, Pat};
+
+use crate::routes::{Location, ROUTE_RECEIVERS, collect_rust_files};
+
+/// The server setup of a Fluxor project, read statically from its sources.
+///
+/// Values passed to `app.run(host, port)` and `app.set_dir(dir)` are followed through `let`
+/// bindings, `.to_string()` / `String::from` and `env_var("KEY", "default")` calls, which are
+/// looked up in the environment and the project's `.env` file.
+#[derive(Debug, Clone, Default)]
+pub struct ServerConfig {
+    pub host: Option<String>,
+    pub port: Option<String>,
+    /// The static file directories, relative to the project root.
+    pub static_dirs: Vec<PathBuf>,
+    /// The `env_var("KEY", "default")` calls of the project.
+    pub env_vars: Vec<EnvVar>,
+}
+
+/// An environment variable read with `env_var(...)`.
+#[derive(Debug, Clone)]
+pub struct EnvVar {
+    pub key: String,
+    /// The value used when the variable is neither set nor in `.env`.
+    pub default: Option<String>,
+    pub location: Location,
+}
+
+impl ServerConfig {
+    /// Reads the server setup of the project at `root`; files that fail to parse are skipped.
+    pub fn load(root: &Path) -> Self {
+        let env = read_dotenv(root);
+        let mut config = ServerConfig::default();
+
+        let mut files = Vec::new();
+        if collect_rust_files(&root.join("src"), &mut files).is_err() {
+            return config;
+        }
+        files.sort();
+
+        for file in files {
+            let Some(syntax) = fs::read_to_string(&file).ok().and_then(|source| syn::parse_file(&source).ok()) else {
+                continue;
+            };
+
+            let mut collector = Collector::default();
+            collector.visit_file(&syntax);
+
+            let relative = file.strip_prefix(root).unwrap_or(&file);
+            for (key, default, line) in collector.env_vars {
+                config.env_vars.push(EnvVar {
+                    key,
+                    default,
+                    location: Location {
+                        file: relative.to_path_buf(),
+                        line,
+                    },
+                });
+            }
+
+            let value = |expr: &Expr| string_value(expr, &collector.bindings, &env, 0);
+            for (method, args) in &collector.calls {
+                match (method.as_str(), args.as_slice()) {
+                    ("run", [host, port]) => {
+                        config.host = config.host.take().or_else(|| value(host));
+                        config.port = config.port.take().or_else(|| value(port));
+                    }
+                    ("set_dir", [dir]) => {
+                        if let Some(dir) = value(dir) {
+                            config.static_dirs.push(PathBuf::from(dir));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        config
+    }
+
+    /// The URL the server listens on, e.g. `http://127.0.0.1:8080`.
+    pub fn url(&self) -> Option<String> {
+        Some(format!("http://{}:{}", self.host.as_ref()?, self.port.as_ref()?))
+    }
+}
+
+/// Reads the `KEY=VALUE` lines of the `.env` file at `root`, if there is one.
+pub fn read_dotenv(root: &Path) -> BTreeMap<String, String> {
+    let Ok(contents) = fs::read_to_string(root.join(".env")) else {
+        return BTreeMap::new();
+    };
+
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            let (key, value) = line.split_once('=')?;
+            let value = value.trim();
+            let value = [('"', '"'), ('\'', '\'')]
+                .iter()
+                .find_map(|(open, close)| value.strip_prefix(*open)?.strip_suffix(*close))
+                .unwrap_or(value);
+            Some((key.trim().to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// Collects `let` bindings, the `run` / `set_dir` calls and the `env_var` keys of one file.
+#[derive(Default)]
+struct Collector {
+    bindings: BTreeMap<String, Expr>,
+    calls: Vec<(String, Vec<Expr>)>,
+    env_vars: Vec<(String, Option<String>, usize)>,
+}
+
+impl<'ast> Visit<'ast> for Collector {
+    fn visit_local(&mut self, local: &'ast Local) {
+        if let (Pat::Ident(pat), Some(init)) = (&local.pat, &local.init) {
+            self.bindings.insert(pat.ident.to_string(), (*init.expr).clone());
+        }
+
+        visit::visit_local(self, local);
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        let on_server = matches!(&*call.receiver, Expr::Path(receiver) if ROUTE_RECEIVERS.iter().any(|name| receiver.path.is_ident(name)));
+        if on_server && (call.method == "run" || call.method == "set_dir") {
+            self.calls.push((call.method.to_string(), call.args.iter().cloned().collect()));
+        }
+
+        visit::visit_expr_method_call(self, call);
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast ExprCall) {
+        let literal = |expr: &Expr| match expr {
+            Expr::Lit(literal) => match &literal.lit {
+                Lit::Str(text) => Some(text.value()),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        if let Expr::Path(function) = &*call.func
+            && function.path.segments.last().is_some_and(|segment| segment.ident == "env_var")
+            && let Some(key) = call.args.first().and_then(literal)
+        {
+            let default = call.args.iter().nth(1).and_then(literal);
+            self.env_vars.push((key, default, function.path.segments[0].ident.span().start().line));
+        }
+
+        visit::visit_expr_call(self, call);
+    }
+}
+
+/// The string an expression evaluates to, when it can be known without running the code.
+fn string_value(expr: &Expr, bindings: &BTreeMap<String, Expr>, env: &BTreeMap<String, String>, depth: usize) -> Option<String> {
+    // Guard against `let x = x;` chains
+    if depth > 8 {
+        return None;
+    }
+    let value = |expr: &Expr| string_value(expr, bindings, env, depth + 1);
+
+    match expr {
+        Expr::Lit(literal) => match &literal.lit {
+            Lit::Str(text) => Some(text.value()),
+            _ => None,
+        },
+        Expr::Reference(reference) => value(&reference.expr),
+        Expr::Paren(paren) => value(&paren.expr),
+        Expr::Path(path) => bindings.get(&path.path.get_ident()?.to_string()).and_then(value),
+        Expr::MethodCall(call) if call.args.is_empty() => {
+            matches!(call.method.to_string().as_str(), "to_string" | "to_owned" | "into" | "clone" | "as_str")
+                .then(|| value(&call.receiver))
+                .flatten()
+        }
+        Expr::Call(call) => {
+            let Expr::Path(function) = &*call.func else {
+                return None;
+            };
+            let name = function.path.segments.last()?.ident.to_string();
+            let args: Vec<&Expr> = call.args.iter().collect();
+
+            match (name.as_str(), args.as_slice()) {
+                ("from", [text]) => value(text),
+                ("env_var", [key, rest @ ..]) => {
+                    let key = value(key)?;
+                    std::env::var(&key)
+                        .ok()
+                        .or_else(|| env.get(&key).cloned())
+                        .or_else(|| rest.first().and_then(|default| value(default)))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
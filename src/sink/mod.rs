@@ -0,0 +1,369 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
+
+use crate::error::{FluxorCliError, Result};
+
+/// Destination for the directories and files produced by the project generators.
+///
+/// Generators only ever see a `&mut dyn ProjectSink` and paths relative to the sink root,
+/// so the same code writes a real project to disk, previews it in memory or packs it into
+/// an archive.
+pub trait ProjectSink {
+    /// Creates `path` and all of its missing parents.
+    fn create_dir(&mut self, path: &Path) -> Result<()>;
+
+    /// Writes `contents` to the file at `path`, replacing any previous contents.
+    fn write_file(&mut self, path: &Path, contents: &[u8]) -> Result<()>;
+
+    /// Marks the already written file at `path` as executable.
+    fn set_executable(&mut self, path: &Path) -> Result<()>;
+}
+
+/// Writes the project to a directory on disk.
+#[derive(Debug)]
+pub struct DiskSink {
+    root: PathBuf,
+}
+
+impl DiskSink {
+    /// Creates a sink that resolves every path relative to `root`.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        DiskSink { root: root.into() }
+    }
+}
+
+impl ProjectSink for DiskSink {
+    fn create_dir(&mut self, path: &Path) -> Result<()> {
+        let path = self.root.join(path);
+        fs::create_dir_all(&path).map_err(|source| FluxorCliError::io(&path, source))
+    }
+
+    fn write_file(&mut self, path: &Path, contents: &[u8]) -> Result<()> {
+        let path = self.root.join(path);
+        fs::write(&path, contents).map_err(|source| FluxorCliError::io(&path, source))
+    }
+
+    #[cfg(unix)]
+    fn set_executable(&mut self, path: &Path) -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = self.root.join(path);
+        let mut permissions = fs::metadata(&path)
+            .map_err(|source| FluxorCliError::io(&path, source))?
+            .permissions();
+        permissions.set_mode(permissions.mode() | 0o111);
+        fs::set_permissions(&path, permissions).map_err(|source| FluxorCliError::io(&path, source))
+    }
+
+    #[cfg(not(unix))]
+    fn set_executable(&mut self, _path: &Path) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Keeps the project in memory, e.g. to preview it with `fluxor new --dry-run`.
+#[derive(Debug, Default)]
+pub struct MemorySink {
+    dirs: BTreeSet<PathBuf>,
+    files: BTreeMap<PathBuf, Vec<u8>>,
+    executables: BTreeSet<PathBuf>,
+}
+
+impl MemorySink {
+    /// Creates an empty in-memory sink.
+    pub fn new() -> Self {
+        MemorySink::default()
+    }
+
+    /// The generated directories, sorted by path.
+    pub fn dirs(&self) -> &BTreeSet<PathBuf> {
+        &self.dirs
+    }
+
+    /// The generated files and their contents, sorted by path.
+    pub fn files(&self) -> &BTreeMap<PathBuf, Vec<u8>> {
+        &self.files
+    }
+
+    /// Whether the file at `path` was marked as executable.
+    pub fn is_executable(&self, path: &Path) -> bool {
+        self.executables.contains(path)
+    }
+
+    /// Renders the generated project as a tree with the size of every file.
+    ///
+    /// Executable files are marked with a trailing `*`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use fluxor_cli::sink::{MemorySink, ProjectSink};
+    ///
+    /// let mut sink = MemorySink::new();
+    /// sink.create_dir(Path::new("app/src")).unwrap();
+    /// sink.write_file(Path::new("app/Cargo.toml"), b"[package]").unwrap();
+    /// sink.write_file(Path::new("app/src/main.rs"), b"fn main() {}").unwrap();
+    ///
+    /// assert_eq!(
+    ///     sink.tree(),
+    ///     "app/\n├── src/\n│   └── main.rs (12 B)\n└── Cargo.toml (9 B)\n"
+    /// );
+    /// ```
+    pub fn tree(&self) -> String {
+        let mut output = String::new();
+        self.render_children(Path::new(""), "", &mut output);
+        output
+    }
+
+    fn children(&self, parent: &Path) -> Vec<(PathBuf, bool)> {
+        let mut children: BTreeMap<PathBuf, bool> = BTreeMap::new();
+
+        for dir in &self.dirs {
+            if dir.parent() == Some(parent) {
+                children.insert(dir.clone(), true);
+            }
+        }
+        for file in self.files.keys() {
+            if file.parent() == Some(parent) {
+                children.insert(file.clone(), false);
+            }
+        }
+
+        // Directories first, then files, each sorted by name
+        let mut children: Vec<(PathBuf, bool)> = children.into_iter().collect();
+        children.sort_by_key(|(path, is_dir)| (!is_dir, path.clone()));
+        children
+    }
+
+    fn render_children(&self, parent: &Path, prefix: &str, output: &mut String) {
+        let children = self.children(parent);
+        let top_level = parent.as_os_str().is_empty();
+
+        for (index, (path, is_dir)) in children.iter().enumerate() {
+            let name = path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+            let last = index + 1 == children.len();
+
+            let (branch, indent) = if top_level {
+                ("", "")
+            } else if last {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+
+            if *is_dir {
+                output.push_str(&format!("{}{}{}/\n", prefix, branch, name));
+                self.render_children(path, &format!("{}{}", prefix, indent), output);
+            } else {
+                let size = self.files.get(path).map(Vec::len).unwrap_or_default();
+                let marker = if self.is_executable(path) { "*" } else { "" };
+                output.push_str(&format!("{}{}{}{} ({} B)\n", prefix, branch, name, marker, size));
+            }
+        }
+    }
+
+    fn add_dir(&mut self, path: &Path) {
+        for ancestor in path.ancestors() {
+            if ancestor.as_os_str().is_empty() {
+                break;
+            }
+            self.dirs.insert(ancestor.to_path_buf());
+        }
+    }
+}
+
+impl ProjectSink for MemorySink {
+    fn create_dir(&mut self, path: &Path) -> Result<()> {
+        self.add_dir(path);
+        Ok(())
+    }
+
+    fn write_file(&mut self, path: &Path, contents: &[u8]) -> Result<()> {
+        // Mirror the disk: writing into a directory that was never created fails
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+            && !self.dirs.contains(parent)
+        {
+            return Err(FluxorCliError::io(path, io::Error::from(io::ErrorKind::NotFound)));
+        }
+
+        self.files.insert(path.to_path_buf(), contents.to_vec());
+        Ok(())
+    }
+
+    fn set_executable(&mut self, path: &Path) -> Result<()> {
+        if !self.files.contains_key(path) {
+            return Err(FluxorCliError::io(path, io::Error::from(io::ErrorKind::NotFound)));
+        }
+
+        self.executables.insert(path.to_path_buf());
+        Ok(())
+    }
+}
+
+/// The archive formats supported by [`ArchiveSink`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    Tar,
+    TarGz,
+    Zip,
+}
+
+impl ArchiveFormat {
+    /// Detects the format from the archive file name (`.tar`, `.tar.gz`, `.tgz` or `.zip`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use fluxor_cli::sink::ArchiveFormat;
+    ///
+    /// assert_eq!(ArchiveFormat::from_path(Path::new("app.tar.gz")), Some(ArchiveFormat::TarGz));
+    /// assert_eq!(ArchiveFormat::from_path(Path::new("app.zip")), Some(ArchiveFormat::Zip));
+    /// assert_eq!(ArchiveFormat::from_path(Path::new("app.rar")), None);
+    /// ```
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_string_lossy().to_lowercase();
+
+        if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(ArchiveFormat::TarGz)
+        } else if name.ends_with(".tar") {
+            Some(ArchiveFormat::Tar)
+        } else if name.ends_with(".zip") {
+            Some(ArchiveFormat::Zip)
+        } else {
+            None
+        }
+    }
+}
+
+/// Packs the project into a tar, tar.gz or zip archive.
+///
+/// Entries are collected in memory and the archive is written by [`ArchiveSink::finish`],
+/// so nothing is created on disk if generation fails.
+#[derive(Debug)]
+pub struct ArchiveSink {
+    path: PathBuf,
+    format: ArchiveFormat,
+    entries: MemorySink,
+}
+
+impl ArchiveSink {
+    /// Creates a sink for the archive at `path`, detecting the format from its extension.
+    pub fn new(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let format = ArchiveFormat::from_path(&path).ok_or_else(|| {
+            FluxorCliError::io(
+                &path,
+                io::Error::new(io::ErrorKind::InvalidInput, "unsupported archive format; use .tar, .tar.gz, .tgz or .zip"),
+            )
+        })?;
+
+        Ok(ArchiveSink {
+            path,
+            format,
+            entries: MemorySink::new(),
+        })
+    }
+
+    /// Writes the archive file.
+    pub fn finish(self) -> Result<()> {
+        let file = File::create(&self.path).map_err(|source| FluxorCliError::io(&self.path, source))?;
+
+        let result = match self.format {
+            ArchiveFormat::Tar => self.write_tar(file).map(drop),
+            ArchiveFormat::TarGz => self
+                .write_tar(GzEncoder::new(file, Compression::default()))
+                .and_then(|encoder| encoder.finish().map(drop)),
+            ArchiveFormat::Zip => self.write_zip(file),
+        };
+
+        result.map_err(|source| FluxorCliError::io(&self.path, source))
+    }
+
+    fn write_tar<W: Write>(&self, writer: W) -> io::Result<W> {
+        let mut builder = tar::Builder::new(writer);
+        let mtime = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+
+        for dir in self.entries.dirs() {
+            let mut header = tar::Header::new_gnu();
+            header.set_entry_type(tar::EntryType::Directory);
+            header.set_mode(0o755);
+            header.set_mtime(mtime);
+            header.set_size(0);
+            builder.append_data(&mut header, archive_name(dir, true), io::empty())?;
+        }
+
+        for (path, contents) in self.entries.files() {
+            let mut header = tar::Header::new_gnu();
+            header.set_mode(self.mode(path));
+            header.set_mtime(mtime);
+            header.set_size(contents.len() as u64);
+            builder.append_data(&mut header, archive_name(path, false), contents.as_slice())?;
+        }
+
+        builder.into_inner()
+    }
+
+    fn write_zip(&self, file: File) -> io::Result<()> {
+        let mut zip = ZipWriter::new(file);
+        let options = SimpleFileOptions::default();
+
+        for dir in self.entries.dirs() {
+            zip.add_directory(archive_name(dir, true), options.unix_permissions(0o755))?;
+        }
+
+        for (path, contents) in self.entries.files() {
+            zip.start_file(archive_name(path, false), options.unix_permissions(self.mode(path)))?;
+            zip.write_all(contents)?;
+        }
+
+        zip.finish()?;
+        Ok(())
+    }
+
+    fn mode(&self, path: &Path) -> u32 {
+        if self.entries.is_executable(path) { 0o755 } else { 0o644 }
+    }
+}
+
+impl ProjectSink for ArchiveSink {
+    fn create_dir(&mut self, path: &Path) -> Result<()> {
+        self.entries.create_dir(path)
+    }
+
+    fn write_file(&mut self, path: &Path, contents: &[u8]) -> Result<()> {
+        self.entries.write_file(path, contents)
+    }
+
+    fn set_executable(&mut self, path: &Path) -> Result<()> {
+        self.entries.set_executable(path)
+    }
+}
+
+/// Archive entry names always use `/` separators; directories end with `/`.
+fn archive_name(path: &Path, is_dir: bool) -> String {
+    let mut name = path
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+
+    if is_dir {
+        name.push('/');
+    }
+
+    name
+}
@@ -0,0 +1,132 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::{Mutex, Once};
+
+use crate::error::{FluxorCliError, Result};
+
+/// Staging directories that must be removed if the process is interrupted.
+static ACTIVE_STAGING_DIRS: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
+static INTERRUPT_HANDLER: Once = Once::new();
+
+/// A temporary directory next to the project directory in which a project is generated.
+///
+/// The project only appears at its final location once [`StagingDir::commit`] renames the
+/// staging directory into place. If the staging directory is dropped before being committed
+/// (because a generator failed) or the process receives Ctrl-C, the staging directory is
+/// removed, so a failed run never leaves a partially populated project behind.
+#[derive(Debug)]
+pub struct StagingDir {
+    path: PathBuf,
+    target: PathBuf,
+    committed: bool,
+}
+
+impl StagingDir {
+    /// Creates the staging directory for `target`, e.g. `.my_app.fluxor-staging-1234` for `my_app`.
+    pub fn create(target: &Path) -> Result<Self> {
+        if target.exists() {
+            return Err(FluxorCliError::DirectoryExists(target.to_path_buf()));
+        }
+
+        let file_name = target
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .ok_or_else(|| FluxorCliError::InvalidName(target.display().to_string()))?;
+        let staging_name = format!(".{}.fluxor-staging-{}", file_name, process::id());
+        let path = match target.parent() {
+            Some(parent) => parent.join(staging_name),
+            None => PathBuf::from(staging_name),
+        };
+
+        // A leftover staging directory from a crashed run with the same pid can be discarded
+        if path.exists() {
+            fs::remove_dir_all(&path).map_err(|source| FluxorCliError::io(&path, source))?;
+        }
+
+        install_interrupt_handler();
+        register(&path);
+
+        if let Err(source) = fs::create_dir_all(&path) {
+            unregister(&path);
+            return Err(FluxorCliError::io(&path, source));
+        }
+
+        Ok(StagingDir {
+            path,
+            target: target.to_path_buf(),
+            committed: false,
+        })
+    }
+
+    /// The directory the project is generated into.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Moves the fully generated project to its final location.
+    pub fn commit(mut self) -> Result<()> {
+        if self.target.exists() {
+            return Err(FluxorCliError::DirectoryExists(self.target.clone()));
+        }
+
+        fs::rename(&self.path, &self.target).map_err(|source| FluxorCliError::io(&self.target, source))?;
+
+        self.committed = true;
+        unregister(&self.path);
+
+        Ok(())
+    }
+}
+
+impl Drop for StagingDir {
+    fn drop(&mut self) {
+        if !self.committed {
+            let _ = fs::remove_dir_all(&self.path);
+            unregister(&self.path);
+        }
+    }
+}
+
+fn register(path: &Path) {
+    if let Ok(mut dirs) = ACTIVE_STAGING_DIRS.lock() {
+        dirs.push(path.to_path_buf());
+    }
+}
+
+fn unregister(path: &Path) {
+    if let Ok(mut dirs) = ACTIVE_STAGING_DIRS.lock() {
+        dirs.retain(|dir| dir != path);
+    }
+}
+
+/// Removes every active staging directory and exits when Ctrl-C is pressed.
+///
+/// The handler stays installed once a project is committed, e.g. while its hooks run; it then
+/// only exits, as the project already exists.
+///
+/// Applications embedding the library may already own the Ctrl-C handler; in that case
+/// installing ours fails and staging directories are only cleaned up on drop.
+fn install_interrupt_handler() {
+    INTERRUPT_HANDLER.call_once(|| {
+        let _ = ctrlc::set_handler(|| {
+            let removed = match ACTIVE_STAGING_DIRS.lock() {
+                Ok(mut dirs) => {
+                    for dir in dirs.iter() {
+                        let _ = fs::remove_dir_all(dir);
+                    }
+                    dirs.drain(..).count()
+                }
+                Err(_) => 0,
+            };
+
+            if removed > 0 {
+                eprintln!("Interrupted; the project was not created.");
+            } else {
+                eprintln!("Interrupted.");
+            }
+            process::exit(130);
+        });
+    });
+}
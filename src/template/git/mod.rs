@@ -0,0 +1,202 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command as ProcessCommand;
+
+use crate::error::{FluxorCliError, Result};
+
+/// A template hosted in a git repository, e.g. `git+https://example.com/starter.git#v2`.
+///
+/// Repositories are cloned once into [`cache_dir`] and reused; [`update_cached`] fetches
+/// the latest commits of every cached clone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitTemplate {
+    /// The URL passed to `git clone`, without the `git+` prefix and the `#ref` suffix.
+    pub url: String,
+    /// The branch, tag or commit to check out; the default branch when `None`.
+    pub reference: Option<String>,
+}
+
+impl GitTemplate {
+    /// Parses a `--template` value naming a git repository.
+    ///
+    /// `git+<url>` always names a repository; `https://`, `ssh://`, `git://` and `git@` URLs and
+    /// paths ending in `.git` do too. Anything else is a local template directory.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fluxor_cli::template::git::GitTemplate;
+    ///
+    /// let template = GitTemplate::parse("git+file:///srv/templates/fluxor-starter.git#v2").unwrap();
+    /// assert_eq!(template.url, "file:///srv/templates/fluxor-starter.git");
+    /// assert_eq!(template.reference.as_deref(), Some("v2"));
+    ///
+    /// assert!(GitTemplate::parse("https://github.com/acme/starter").is_some());
+    /// assert!(GitTemplate::parse("../acme-starter").is_none());
+    /// ```
+    pub fn parse(spec: &str) -> Option<Self> {
+        let (url, reference) = match spec.rsplit_once('#') {
+            Some((url, reference)) => (url, Some(reference)),
+            None => (spec, None),
+        };
+
+        let url = match url.strip_prefix("git+") {
+            Some(url) => url,
+            None if ["https://", "http://", "ssh://", "git://", "git@"].iter().any(|prefix| url.starts_with(prefix)) => url,
+            None if url.trim_end_matches('/').ends_with(".git") => url,
+            None => return None,
+        };
+
+        Some(GitTemplate {
+            url: url.to_string(),
+            reference: reference.filter(|reference| !reference.is_empty()).map(str::to_string),
+        })
+    }
+
+    /// The repository name, e.g. `fluxor-starter`; templates without a name in their manifest use it.
+    pub fn name(&self) -> &str {
+        self.url
+            .trim_end_matches('/')
+            .rsplit(['/', ':'])
+            .next()
+            .map(|name| name.trim_end_matches(".git"))
+            .filter(|name| !name.is_empty())
+            .unwrap_or("template")
+    }
+
+    /// The directory of the cached clone, named after the repository and a hash of its URL.
+    pub fn clone_dir(&self) -> Option<PathBuf> {
+        Some(cache_dir()?.join(format!("{}-{:016x}", self.name(), fnv1a(self.url.as_bytes()))))
+    }
+
+    /// Clones the repository into the cache if needed and checks out the requested ref,
+    /// returning the directory of the working tree.
+    ///
+    /// A cached clone is reused as it is; it is only fetched when the ref is not in it yet,
+    /// and never with `offline`.
+    pub fn checkout(&self, offline: bool) -> Result<PathBuf> {
+        // Neither may be read as an option of git
+        if self.url.starts_with('-') {
+            return Err(self.error("the URL must not start with '-'"));
+        }
+        if self.reference.as_ref().is_some_and(|reference| reference.starts_with('-')) {
+            return Err(self.unknown_reference());
+        }
+
+        let dir = self.clone_dir().ok_or_else(|| self.error("the cache directory (~/.cache) was not found"))?;
+
+        if !dir.join(".git").is_dir() {
+            if offline {
+                return Err(self.error("the repository is not cached and --offline was given"));
+            }
+
+            let parent = dir.parent().unwrap_or(&dir);
+            fs::create_dir_all(parent).map_err(|source| FluxorCliError::io(parent, source))?;
+            println!("Cloning '{}'...", self.url);
+            if let Err(error) = git(parent, &["clone", "--quiet", "--", &self.url, &dir.to_string_lossy()]) {
+                // Do not leave a broken clone behind for the next run
+                let _ = fs::remove_dir_all(&dir);
+                return Err(self.error(&error));
+            }
+        }
+
+        let commit = match self.resolve(&dir) {
+            Some(commit) => commit,
+            None if !offline => {
+                git(&dir, &["fetch", "--quiet", "--tags", "--force", "origin"]).map_err(|error| self.error(&error))?;
+                self.resolve(&dir).ok_or_else(|| self.unknown_reference())?
+            }
+            None => return Err(self.unknown_reference()),
+        };
+
+        git(&dir, &["checkout", "--quiet", "--force", "--detach", &commit]).map_err(|error| self.error(&error))?;
+
+        Ok(dir)
+    }
+
+    /// The commit the requested ref points to in the clone at `dir`; remote branches take
+    /// precedence over the stale local ones.
+    fn resolve(&self, dir: &Path) -> Option<String> {
+        let candidates = match &self.reference {
+            Some(reference) => vec![format!("origin/{}", reference), reference.clone()],
+            None => vec!["origin/HEAD".to_string()],
+        };
+
+        candidates
+            .iter()
+            .find_map(|candidate| git(dir, &["rev-parse", "--verify", "--quiet", &format!("{}^{{commit}}", candidate)]).ok())
+    }
+
+    fn unknown_reference(&self) -> FluxorCliError {
+        self.error(&format!("no branch, tag or commit named '{}'", self.reference.as_deref().unwrap_or("HEAD")))
+    }
+
+    fn error(&self, message: &str) -> FluxorCliError {
+        FluxorCliError::TemplateFetch {
+            url: self.url.clone(),
+            message: message.to_string(),
+        }
+    }
+}
+
+/// Where git templates are cloned: `$XDG_CACHE_HOME/fluxor/templates`, by default
+/// `~/.cache/fluxor/templates`.
+pub fn cache_dir() -> Option<PathBuf> {
+    let cache = match env::var_os("XDG_CACHE_HOME").filter(|cache| !cache.is_empty()) {
+        Some(cache) => PathBuf::from(cache),
+        None => PathBuf::from(env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?).join(".cache"),
+    };
+
+    Some(cache.join("fluxor").join("templates"))
+}
+
+/// Runs `fluxor templates update`: fetches the latest commits of every cached clone.
+pub fn update_cached() -> Result<()> {
+    let Some(cache) = cache_dir().filter(|cache| cache.is_dir()) else {
+        println!("No templates are cached.");
+        return Ok(());
+    };
+
+    let mut clones = fs::read_dir(&cache)
+        .and_then(|entries| entries.map(|entry| entry.map(|entry| entry.path())).collect::<std::io::Result<Vec<_>>>())
+        .map_err(|source| FluxorCliError::io(&cache, source))?;
+    clones.retain(|dir| dir.join(".git").is_dir());
+    clones.sort();
+
+    if clones.is_empty() {
+        println!("No templates are cached.");
+    }
+
+    for dir in clones {
+        let url = git(&dir, &["remote", "get-url", "origin"]).unwrap_or_else(|_| dir.display().to_string());
+        git(&dir, &["fetch", "--quiet", "--tags", "--force", "--prune", "origin"])
+            .map_err(|message| FluxorCliError::TemplateFetch { url: url.clone(), message })?;
+        println!("Updated '{}'.", url);
+    }
+
+    Ok(())
+}
+
+/// Runs `git` in `dir`, returning its trimmed output or the first line of its error.
+fn git(dir: &Path, args: &[&str]) -> std::result::Result<String, String> {
+    let output = ProcessCommand::new("git")
+        .current_dir(dir)
+        .args(args)
+        .output()
+        .map_err(|e| format!("failed to run git: {}", e))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(stderr.lines().next().unwrap_or("git failed").trim().to_string())
+    }
+}
+
+/// 64-bit FNV-1a, a stable hash for naming cache directories.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
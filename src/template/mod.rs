@@ -0,0 +1,338 @@
+pub mod git;
+pub mod variables;
+
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use toml_edit::DocumentMut;
+
+use crate::error::{FluxorCliError, Result};
+use crate::examples::DependencyVersions;
+use crate::hooks::Hook;
+use crate::sink::ProjectSink;
+use crate::utils::copy_folder_dir_with;
+use git::GitTemplate;
+use variables::{Condition, Values, Variable, render_conditionals};
+
+/// The manifest every template directory must contain.
+pub const MANIFEST_FILE: &str = "fluxor-template.toml";
+
+/// Entries of a template directory that are never copied into the project.
+const IGNORED: &[&str] = &[MANIFEST_FILE, ".git", "target"];
+
+/// A project template read from a directory or git repository passed to `fluxor new --template`.
+///
+/// The directory holds the files of the generated project and a `fluxor-template.toml` manifest:
+///
+/// ```toml
+/// [template]
+/// name = "acme-starter"
+/// description = "Our starter with auth and logging"
+/// dependencies = ["fluxor", "crator"]
+/// hooks = ["git-init", { copy = ".env.example", to = ".env" }]
+///
+/// [variables.with_db]
+/// prompt = "Add a database module?"
+/// default = false
+///
+/// [conditions]
+/// "src/db" = "with_db"
+/// ```
+///
+/// Every UTF-8 file is rendered like the built-in examples: `{{cratename}}` becomes the package
+/// name, `{{<dependency>_version}}` (e.g. `{{fluxor_version}}`) the resolved version of each
+/// dependency and `{{<variable>}}` the value of each [`Variable`]. Other `{{...}}` placeholders,
+/// such as those of cans templates, are left untouched.
+///
+/// Files and directories listed under `[conditions]` are only generated when their [`Condition`]
+/// holds, lines can be kept or dropped with `{{#if ...}}` blocks (see [`render_conditionals`]),
+/// and placeholders in file and directory names rename them.
+#[derive(Debug, Clone)]
+pub struct Template {
+    /// The template directory.
+    pub dir: PathBuf,
+    /// The name shown in messages; defaults to the directory or repository name.
+    pub name: String,
+    pub description: Option<String>,
+    /// The crates whose versions are resolved; defaults to `["fluxor"]`.
+    pub dependencies: Vec<String>,
+    /// The hooks run after the project is generated, in order.
+    pub hooks: Vec<Hook>,
+    /// The variables, in the order they are prompted for.
+    pub variables: Vec<Variable>,
+    /// Files and directories, relative to the template directory, generated only under a condition.
+    pub conditions: Vec<(PathBuf, Condition)>,
+}
+
+impl Template {
+    /// Reads the template named by a `--template` value: a git repository (see
+    /// [`GitTemplate::parse`]), which is cloned into the cache first, or a local directory.
+    pub fn open(spec: &str, offline: bool) -> Result<Self> {
+        match GitTemplate::parse(spec) {
+            Some(repository) => {
+                let dir = repository.checkout(offline)?;
+                Self::read(&dir, repository.name())
+            }
+            None => Self::load(Path::new(spec)),
+        }
+    }
+
+    /// Reads the template in `dir`.
+    pub fn load(dir: &Path) -> Result<Self> {
+        let name = dir
+            .canonicalize()
+            .ok()
+            .and_then(|dir| dir.file_name().map(|name| name.to_string_lossy().into_owned()))
+            .unwrap_or_else(|| "template".to_string());
+        Self::read(dir, &name)
+    }
+
+    fn read(dir: &Path, default_name: &str) -> Result<Self> {
+        let invalid = |message: &str| FluxorCliError::InvalidTemplate {
+            path: dir.to_path_buf(),
+            message: message.to_string(),
+        };
+
+        if !dir.is_dir() {
+            return Err(invalid("not a directory"));
+        }
+        let manifest_path = dir.join(MANIFEST_FILE);
+        let manifest = fs::read_to_string(&manifest_path).map_err(|_| invalid(&format!("{} not found", MANIFEST_FILE)))?;
+        let manifest = manifest.parse::<DocumentMut>().map_err(|e| FluxorCliError::InvalidTemplate {
+            path: manifest_path.clone(),
+            message: e.to_string(),
+        })?;
+        if !dir.join("Cargo.toml").is_file() {
+            return Err(invalid("the template has no Cargo.toml"));
+        }
+
+        let table = manifest
+            .get("template")
+            .and_then(|table| table.as_table_like())
+            .ok_or_else(|| invalid(&format!("{} has no [template] table", MANIFEST_FILE)))?;
+        let text = |key: &str| -> Result<Option<String>> {
+            match table.get(key) {
+                None => Ok(None),
+                Some(item) => item
+                    .as_str()
+                    .map(|value| Some(value.to_string()))
+                    .ok_or_else(|| invalid(&format!("template.{} must be a string", key))),
+            }
+        };
+
+        let name = text("name")?.unwrap_or_else(|| default_name.to_string());
+        let description = text("description")?;
+
+        let dependencies = match table.get("dependencies") {
+            None => vec!["fluxor".to_string()],
+            Some(item) => item
+                .as_array()
+                .and_then(|array| array.iter().map(|value| value.as_str().map(str::to_string)).collect::<Option<Vec<_>>>())
+                .ok_or_else(|| invalid("template.dependencies must be an array of crate names"))?,
+        };
+
+        let hooks = match table.get("hooks") {
+            None => Vec::new(),
+            Some(item) => item
+                .as_array()
+                .ok_or_else(|| invalid("template.hooks must be an array"))?
+                .iter()
+                .map(|value| Hook::from_manifest(&toml_edit::Item::Value(value.clone())).map_err(|e| invalid(&e)))
+                .collect::<Result<Vec<_>>>()?,
+        };
+
+        let mut variables = Vec::new();
+        if let Some(item) = manifest.get("variables") {
+            let table = item.as_table_like().ok_or_else(|| invalid("[variables] must be a table"))?;
+            for (name, item) in table.iter() {
+                variables.push(Variable::from_manifest(name, item).map_err(|e| invalid(&e))?);
+            }
+        }
+
+        let mut conditions = Vec::new();
+        if let Some(item) = manifest.get("conditions") {
+            let table = item.as_table_like().ok_or_else(|| invalid("[conditions] must be a table"))?;
+            for (path, item) in table.iter() {
+                let condition = item
+                    .as_str()
+                    .ok_or_else(|| invalid(&format!("conditions.\"{}\" must be a string", path)))
+                    .and_then(|expression| Condition::parse(expression).map_err(|e| invalid(&e)))?;
+                if !variables.iter().any(|variable| variable.name == condition.variable) {
+                    return Err(invalid(&format!("conditions.\"{}\" uses the undeclared variable '{}'", path, condition.variable)));
+                }
+                conditions.push((PathBuf::from(path), condition));
+            }
+        }
+
+        Ok(Template {
+            dir: dir.to_path_buf(),
+            name,
+            description,
+            dependencies,
+            hooks,
+            variables,
+            conditions,
+        })
+    }
+
+    /// Copies the rendered template into `project_path` of `sink`, given the `values` of its variables.
+    pub fn generate(
+        &self,
+        sink: &mut dyn ProjectSink,
+        project_path: &Path,
+        package_name: &str,
+        versions: &DependencyVersions,
+        values: &Values,
+    ) -> Result<()> {
+        let mut placeholders = vec![("cratename".to_string(), package_name.to_string())];
+        for (crate_name, version) in versions {
+            placeholders.push((format!("{}_version", crate_name), version.clone()));
+        }
+        placeholders.extend(values.iter().map(|(name, value)| (name.clone(), value.clone())));
+
+        let mut sink = RenamingSink {
+            sink,
+            project_path,
+            placeholders: &placeholders,
+        };
+
+        copy_folder_dir_with(
+            &mut sink,
+            &self.dir,
+            Path::new(""),
+            &|path| {
+                !IGNORED.iter().any(|ignored| path == Path::new(ignored))
+                    && self.conditions.iter().all(|(conditional, condition)| path != conditional || condition.eval(values))
+            },
+            &mut |path, contents| match String::from_utf8(contents) {
+                Ok(text) => {
+                    let text = render_conditionals(&text, values).map_err(|message| FluxorCliError::InvalidTemplate {
+                        path: self.dir.join(path),
+                        message,
+                    })?;
+                    Ok(render_placeholders(&text, &placeholders).into_bytes())
+                }
+                // Images, fonts and other binary files are copied as they are
+                Err(error) => Ok(error.into_bytes()),
+            },
+        )
+    }
+}
+
+/// Renders the placeholders in the paths of a template before passing them to `sink`.
+struct RenamingSink<'a> {
+    sink: &'a mut dyn ProjectSink,
+    project_path: &'a Path,
+    placeholders: &'a [(String, String)],
+}
+
+impl RenamingSink<'_> {
+    fn rename(&self, path: &Path) -> Result<PathBuf> {
+        let mut renamed = self.project_path.to_path_buf();
+        for component in path.components() {
+            let name = component.as_os_str().to_string_lossy();
+            let rendered = render_placeholders(&name, self.placeholders);
+            // A value must not move the file out of its directory
+            let mut components = Path::new(&rendered).components();
+            match (components.next(), components.next()) {
+                (Some(Component::Normal(_)), None) => renamed.push(rendered),
+                _ => {
+                    return Err(FluxorCliError::InvalidTemplate {
+                        path: path.to_path_buf(),
+                        message: format!("'{}' renders to the invalid file name '{}'", name, rendered),
+                    });
+                }
+            }
+        }
+        Ok(renamed)
+    }
+}
+
+impl ProjectSink for RenamingSink<'_> {
+    fn create_dir(&mut self, path: &Path) -> Result<()> {
+        let path = self.rename(path)?;
+        self.sink.create_dir(&path)
+    }
+
+    fn write_file(&mut self, path: &Path, contents: &[u8]) -> Result<()> {
+        let path = self.rename(path)?;
+        self.sink.write_file(&path, contents)
+    }
+
+    fn set_executable(&mut self, path: &Path) -> Result<()> {
+        let path = self.rename(path)?;
+        self.sink.set_executable(&path)
+    }
+}
+
+/// Replaces every `{{key}}` placeholder of `content` with its value, like `cans::do_replace!`
+/// does for keys known at compile time.
+///
+/// Placeholders are replaced in a single pass, so values are inserted as they are: a `--set`
+/// value that contains `{{other}}` is not expanded again. Unknown placeholders are kept.
+///
+/// # Examples
+///
+/// ```rust
+/// use fluxor_cli::template::render_placeholders;
+///
+/// let variables = [("cratename".to_string(), "my_app".to_string())];
+/// assert_eq!(render_placeholders("use {{cratename}}::routes; {{title}}", &variables), "use my_app::routes; {{title}}");
+/// ```
+pub fn render_placeholders(content: &str, variables: &[(String, String)]) -> String {
+    let mut rendered = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some(start) = rest.find("{{") {
+        rendered.push_str(&rest[..start]);
+        let placeholder = &rest[start + 2..];
+        let value = placeholder.find("}}").and_then(|end| {
+            variables
+                .iter()
+                .find(|(key, _)| *key == placeholder[..end])
+                .map(|(key, value)| (key.len(), value))
+        });
+
+        match value {
+            Some((len, value)) => {
+                rendered.push_str(value);
+                rest = &placeholder[len + 2..];
+            }
+            // Keep the first brace, a placeholder may start at the next one as in `{{{key}}}`
+            None => {
+                rendered.push('{');
+                rest = &rest[start + 1..];
+            }
+        }
+    }
+    rendered.push_str(rest);
+
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variables(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+    }
+
+    #[test]
+    fn values_are_not_expanded_again() {
+        let variables = variables(&[("title", "{{secret}} and {{title}}"), ("secret", "hunter2")]);
+
+        assert_eq!(render_placeholders("<h1>{{title}}</h1>", &variables), "<h1>{{secret}} and {{title}}</h1>");
+        assert_eq!(render_placeholders("{{secret}}{{title}}", &variables), "hunter2{{secret}} and {{title}}");
+    }
+
+    #[test]
+    fn unknown_and_unclosed_placeholders_are_kept() {
+        let variables = variables(&[("name", "app")]);
+
+        assert_eq!(render_placeholders("{{other}} {{name}} {{name", &variables), "{{other}} app {{name");
+        assert_eq!(render_placeholders("{{{name}}} {{ name }}", &variables), "{app} {{ name }}");
+        assert_eq!(render_placeholders("héllo {{name}}ü", &variables), "héllo appü");
+        assert_eq!(render_placeholders("no placeholders", &[]), "no placeholders");
+    }
+}
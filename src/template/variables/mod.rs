@@ -0,0 +1,354 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::Path;
+
+use regex::Regex;
+use toml_edit::{DocumentMut, Item, Value};
+
+use crate::error::{FluxorCliError, Result};
+
+/// The values of a template's variables, keyed by name; booleans are `true` or `false`.
+pub type Values = BTreeMap<String, String>;
+
+/// The type of a template variable.
+#[derive(Debug, Clone)]
+pub enum VariableKind {
+    /// Free text, optionally required to fully match a regex `pattern`.
+    String { pattern: Option<Regex> },
+    Bool,
+    /// One of a fixed list of values.
+    Choice(Vec<String>),
+}
+
+/// A variable declared in the `[variables]` table of a template manifest:
+///
+/// ```toml
+/// [variables.with_db]
+/// type = "bool"
+/// prompt = "Add a database module?"
+/// default = false
+///
+/// [variables.css]
+/// choices = ["plain", "tailwind"]
+/// default = "plain"
+///
+/// [variables.author]
+/// pattern = "[A-Za-z ]+"
+/// ```
+///
+/// `type` is inferred from `choices` or a boolean `default` when omitted.
+#[derive(Debug, Clone)]
+pub struct Variable {
+    pub name: String,
+    pub kind: VariableKind,
+    /// The question asked interactively; defaults to the variable name.
+    pub prompt: Option<String>,
+    /// The value used when none is given; variables without one must be set.
+    pub default: Option<String>,
+}
+
+impl Variable {
+    /// Reads the declaration of the variable `name` from its manifest table.
+    pub(crate) fn from_manifest(name: &str, item: &Item) -> std::result::Result<Self, String> {
+        let table = item.as_table_like().ok_or_else(|| format!("variables.{} must be a table", name))?;
+        let text = |key: &str| -> std::result::Result<Option<String>, String> {
+            match table.get(key) {
+                None => Ok(None),
+                Some(item) => item
+                    .as_str()
+                    .map(|value| Some(value.to_string()))
+                    .ok_or_else(|| format!("variables.{}.{} must be a string", name, key)),
+            }
+        };
+
+        let default = match table.get("default").and_then(Item::as_value) {
+            None => None,
+            Some(Value::Boolean(value)) => Some(value.value().to_string()),
+            Some(Value::String(value)) => Some(value.value().clone()),
+            Some(_) => return Err(format!("variables.{}.default must be a string or a boolean", name)),
+        };
+        let choices = match table.get("choices") {
+            None => None,
+            Some(item) => Some(
+                item.as_array()
+                    .and_then(|array| array.iter().map(|value| value.as_str().map(str::to_string)).collect::<Option<Vec<_>>>())
+                    .filter(|choices| !choices.is_empty())
+                    .ok_or_else(|| format!("variables.{}.choices must be a non-empty array of strings", name))?,
+            ),
+        };
+
+        let kind = match (text("type")?.as_deref(), choices) {
+            (Some("choice") | None, Some(choices)) => VariableKind::Choice(choices),
+            (Some("choice"), None) => return Err(format!("variables.{} is a choice without choices", name)),
+            (Some("bool"), None) => VariableKind::Bool,
+            (None, None) if table.get("default").is_some_and(Item::is_bool) => VariableKind::Bool,
+            (Some("string") | None, None) => {
+                let pattern = match text("pattern")? {
+                    Some(pattern) => Some(
+                        Regex::new(&format!("^(?:{})$", pattern))
+                            .map_err(|e| format!("variables.{}.pattern is not a valid regex: {}", name, e))?,
+                    ),
+                    None => None,
+                };
+                VariableKind::String { pattern }
+            }
+            (Some(kind), _) => return Err(format!("variables.{}.type must be string, bool or choice, not '{}'", name, kind)),
+        };
+
+        let variable = Variable {
+            name: name.to_string(),
+            kind,
+            prompt: text("prompt")?,
+            default: None,
+        };
+        let default = default
+            .map(|default| variable.validate(&default).map_err(|e| format!("variables.{}.default: {}", name, e)))
+            .transpose()?;
+
+        Ok(Variable { default, ..variable })
+    }
+
+    /// Checks `value` against the variable's type, returning it normalized.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fluxor_cli::template::variables::{Variable, VariableKind};
+    ///
+    /// let with_db = Variable { name: "with_db".to_string(), kind: VariableKind::Bool, prompt: None, default: None };
+    /// assert_eq!(with_db.validate("yes"), Ok("true".to_string()));
+    /// assert!(with_db.validate("maybe").is_err());
+    /// ```
+    pub fn validate(&self, value: &str) -> std::result::Result<String, String> {
+        match &self.kind {
+            VariableKind::String { pattern: Some(pattern) } if !pattern.is_match(value) => Err(format!(
+                "'{}' does not match the pattern {}",
+                value,
+                pattern.as_str().trim_start_matches("^(?:").trim_end_matches(")$")
+            )),
+            VariableKind::String { .. } => Ok(value.to_string()),
+            VariableKind::Bool => match value.trim().to_lowercase().as_str() {
+                "true" | "yes" | "y" | "1" => Ok("true".to_string()),
+                "false" | "no" | "n" | "0" => Ok("false".to_string()),
+                _ => Err(format!("'{}' is not a boolean (true or false)", value)),
+            },
+            VariableKind::Choice(choices) if choices.iter().any(|choice| choice == value) => Ok(value.to_string()),
+            VariableKind::Choice(choices) => Err(format!("'{}' is not one of: {}", value, choices.join(", "))),
+        }
+    }
+}
+
+/// A condition from the `[conditions]` table or an `{{#if ...}}` block: `name`, `!name`,
+/// `name == value` or `name != value`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Condition {
+    pub variable: String,
+    /// The value compared with; `None` tests that the variable is `true` (or a non-empty string).
+    pub value: Option<String>,
+    pub negated: bool,
+}
+
+impl Condition {
+    /// Parses a condition.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fluxor_cli::template::variables::{Condition, Values};
+    ///
+    /// let values = Values::from([("css".to_string(), "tailwind".to_string())]);
+    /// assert!(Condition::parse("css == tailwind").unwrap().eval(&values));
+    /// assert!(!Condition::parse("css != \"tailwind\"").unwrap().eval(&values));
+    /// ```
+    pub fn parse(expression: &str) -> std::result::Result<Self, String> {
+        let expression = expression.trim();
+        let unquote = |value: &str| {
+            let value = value.trim();
+            ['"', '\'']
+                .iter()
+                .find_map(|quote| value.strip_prefix(*quote)?.strip_suffix(*quote))
+                .unwrap_or(value)
+                .to_string()
+        };
+
+        let (variable, value, negated) = if let Some((variable, value)) = expression.split_once("!=") {
+            (variable.trim(), Some(unquote(value)), true)
+        } else if let Some((variable, value)) = expression.split_once("==") {
+            (variable.trim(), Some(unquote(value)), false)
+        } else if let Some(variable) = expression.strip_prefix('!') {
+            (variable.trim(), None, true)
+        } else {
+            (expression, None, false)
+        };
+
+        if variable.is_empty() || !variable.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(format!("invalid condition '{}'", expression));
+        }
+
+        Ok(Condition {
+            variable: variable.to_string(),
+            value,
+            negated,
+        })
+    }
+
+    /// Whether the condition holds for `values`.
+    pub fn eval(&self, values: &Values) -> bool {
+        let current = values.get(&self.variable).map(String::as_str).unwrap_or_default();
+        let holds = match &self.value {
+            Some(value) => current == value,
+            None => !current.is_empty() && current != "false",
+        };
+        holds != self.negated
+    }
+}
+
+/// Resolves the value of every variable: `--set` values win over the `--values` file, then the
+/// user is prompted when the terminal is interactive, and the defaults are used otherwise.
+pub fn resolve_values(variables: &[Variable], set: &[(String, String)], values_file: Option<&Path>) -> Result<Values> {
+    let invalid = |variable: &str, message: String| FluxorCliError::TemplateValue {
+        variable: variable.to_string(),
+        message,
+    };
+
+    let mut given: Vec<(String, String)> = match values_file {
+        Some(path) => read_values_file(path)?,
+        None => Vec::new(),
+    };
+    given.extend(set.iter().cloned());
+
+    if let Some((name, _)) = given.iter().find(|(name, _)| !variables.iter().any(|variable| variable.name == *name)) {
+        let names: Vec<&str> = variables.iter().map(|variable| variable.name.as_str()).collect();
+        return Err(invalid(name, format!("the template has no such variable; its variables are: {}", names.join(", "))));
+    }
+
+    let interactive = io::stdin().is_terminal() && io::stdout().is_terminal();
+    let mut values = Values::new();
+    for variable in variables {
+        let value = match given.iter().rev().find(|(name, _)| *name == variable.name) {
+            Some((_, value)) => variable.validate(value).map_err(|e| invalid(&variable.name, e))?,
+            None if interactive => prompt(variable)?,
+            None => variable.default.clone().ok_or_else(|| {
+                invalid(&variable.name, format!("no value given; pass --set {}=VALUE", variable.name))
+            })?,
+        };
+        values.insert(variable.name.clone(), value);
+    }
+
+    Ok(values)
+}
+
+/// Reads the top-level `key = value` pairs of a `--values` TOML file.
+fn read_values_file(path: &Path) -> Result<Vec<(String, String)>> {
+    let contents = fs::read_to_string(path).map_err(|source| FluxorCliError::io(path, source))?;
+    let document = contents.parse::<DocumentMut>().map_err(|e| FluxorCliError::Manifest {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })?;
+
+    document
+        .iter()
+        .map(|(key, item)| {
+            let value = match item.as_value() {
+                Some(Value::String(value)) => value.value().clone(),
+                Some(Value::Boolean(value)) => value.value().to_string(),
+                Some(Value::Integer(value)) => value.value().to_string(),
+                Some(Value::Float(value)) => value.value().to_string(),
+                _ => {
+                    return Err(FluxorCliError::Manifest {
+                        path: path.to_path_buf(),
+                        message: format!("{} must be a string, boolean or number", key),
+                    });
+                }
+            };
+            Ok((key.to_string(), value))
+        })
+        .collect()
+}
+
+/// Asks for the value of `variable` until a valid one is entered.
+fn prompt(variable: &Variable) -> Result<String> {
+    let question = variable.prompt.as_deref().unwrap_or(&variable.name);
+    let hint = match &variable.kind {
+        VariableKind::Bool => " (y/n)".to_string(),
+        VariableKind::Choice(choices) => format!(" ({})", choices.join("/")),
+        VariableKind::String { .. } => String::new(),
+    };
+    let default = variable.default.as_deref().map(|default| format!(" [{}]", default)).unwrap_or_default();
+
+    loop {
+        print!("{}{}{}: ", question, hint, default);
+        io::stdout().flush().map_err(|source| FluxorCliError::io("<stdout>", source))?;
+
+        let mut answer = String::new();
+        let read = io::stdin().lock().read_line(&mut answer).map_err(|source| FluxorCliError::io("<stdin>", source))?;
+        let answer = answer.trim();
+
+        let value = match (answer, &variable.default) {
+            // End of input: nothing more can be asked
+            _ if read == 0 => {
+                return variable.default.clone().ok_or_else(|| FluxorCliError::TemplateValue {
+                    variable: variable.name.clone(),
+                    message: "no value given".to_string(),
+                });
+            }
+            ("", Some(default)) => return Ok(default.clone()),
+            (answer, _) => variable.validate(answer),
+        };
+
+        match value {
+            Ok(value) => return Ok(value),
+            Err(message) => println!("{}", message),
+        }
+    }
+}
+
+/// Keeps the lines between `{{#if condition}}`, `{{else}}` and `{{/if}}` tags, each on its own
+/// line, according to `values`; blocks can be nested.
+///
+/// # Examples
+///
+/// ```rust
+/// use fluxor_cli::template::variables::{Values, render_conditionals};
+///
+/// let values = Values::from([("with_db".to_string(), "false".to_string())]);
+/// let lib_rs = "pub mod routes;\n{{#if with_db}}\npub mod db;\n{{/if}}\n";
+/// assert_eq!(render_conditionals(lib_rs, &values).unwrap(), "pub mod routes;\n");
+/// ```
+pub fn render_conditionals(content: &str, values: &Values) -> std::result::Result<String, String> {
+    if !content.contains("{{#if") {
+        return Ok(content.to_string());
+    }
+
+    let mut output = String::with_capacity(content.len());
+    // Whether each open block, and every block around it, is being kept
+    let mut blocks: Vec<(bool, bool)> = Vec::new();
+    let active = |blocks: &[(bool, bool)]| blocks.last().is_none_or(|(_, keep)| *keep);
+
+    for (number, line) in content.split_inclusive('\n').enumerate() {
+        let tag = line.trim();
+        if let Some(expression) = tag.strip_prefix("{{#if ").and_then(|tag| tag.strip_suffix("}}")) {
+            let condition = Condition::parse(expression).map_err(|e| format!("line {}: {}", number + 1, e))?;
+            let outer = active(&blocks);
+            blocks.push((outer, outer && condition.eval(values)));
+        } else if tag == "{{else}}" {
+            let Some((outer, keep)) = blocks.pop() else {
+                return Err(format!("line {}: {{{{else}}}} without {{{{#if}}}}", number + 1));
+            };
+            blocks.push((outer, outer && !keep));
+        } else if tag == "{{/if}}" {
+            if blocks.pop().is_none() {
+                return Err(format!("line {}: {{{{/if}}}} without {{{{#if}}}}", number + 1));
+            }
+        } else if active(&blocks) {
+            output.push_str(line);
+        }
+    }
+
+    if !blocks.is_empty() {
+        return Err("{{#if}} without {{/if}}".to_string());
+    }
+
+    Ok(output)
+}
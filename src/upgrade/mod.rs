@@ -0,0 +1,435 @@
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use cargo_metadata::MetadataCommand;
+use semver::{Comparator, Op, Version, VersionReq};
+use toml_edit::{DocumentMut, Item, TableLike, Value};
+
+use crate::error::{FluxorCliError, Result};
+use crate::version::{VersionResolver, VersionSpec, fetch_latest_version};
+
+/// The crates `fluxor upgrade` updates; crator is only updated where the project already uses it,
+/// as the `fluxor-template` example does.
+pub const UPGRADED_CRATES: &[&str] = &["fluxor", "crator"];
+
+/// The dependency tables searched for the upgraded crates.
+const DEPENDENCY_TABLES: &[&[&str]] = &[&["dependencies"], &["dev-dependencies"], &["workspace", "dependencies"]];
+
+/// Options of `fluxor upgrade`.
+#[derive(Debug, Clone)]
+pub struct Upgrade<'a> {
+    /// The fluxor version: `latest`, an exact version or a requirement.
+    pub to: &'a str,
+    /// Let `latest` cross a semver-incompatible release, e.g. crator 0.7 to 1.x.
+    pub major: bool,
+    /// Upgrade every member of the workspace instead of the current package.
+    pub workspace: bool,
+    /// Resolve `latest` from the local Cargo registry cache only.
+    pub offline: bool,
+}
+
+impl Upgrade<'_> {
+    /// Upgrades the package containing `directory`, or its whole workspace.
+    pub fn upgrade(&self, directory: &Path) -> Result<()> {
+        VersionSpec::parse("fluxor", self.to)?;
+
+        let manifests = self.manifests(directory)?;
+
+        let mut documents = Vec::new();
+        for manifest_path in manifests {
+            let current = fs::read_to_string(&manifest_path).map_err(|source| FluxorCliError::io(&manifest_path, source))?;
+            let document = current.parse::<DocumentMut>().map_err(|e| FluxorCliError::Manifest {
+                path: manifest_path.clone(),
+                message: e.to_string(),
+            })?;
+            documents.push((manifest_path, current, document));
+        }
+
+        // Resolve every version before writing anything
+        let mut targets: Vec<(&str, BTreeMap<String, String>)> = Vec::new();
+        for crate_name in UPGRADED_CRATES {
+            let mut versions = BTreeMap::new();
+            for (_, _, document) in &documents {
+                for current in current_versions(document, crate_name) {
+                    if let Entry::Vacant(entry) = versions.entry(current) {
+                        let target = self.target_version(crate_name, entry.key())?;
+                        entry.insert(target);
+                    }
+                }
+            }
+            if !versions.is_empty() {
+                targets.push((crate_name, versions));
+            }
+        }
+
+        let mut updates = Vec::new();
+        for (manifest_path, current, mut document) in documents {
+            for (crate_name, versions) in &targets {
+                set_version(&mut document, crate_name, &|current| versions[current].clone());
+            }
+
+            let updated = document.to_string();
+            if updated != current {
+                updates.push((manifest_path, current, updated));
+            }
+        }
+
+        let changed = updates.len();
+        for (manifest_path, current, updated) in updates {
+            print!("{}", diff(&manifest_path.display().to_string(), &current, &updated));
+            fs::write(&manifest_path, updated).map_err(|source| FluxorCliError::io(&manifest_path, source))?;
+        }
+
+        let mut versions: Vec<String> = Vec::new();
+        for (crate_name, targets) in &targets {
+            for target in targets.values() {
+                let version = format!("{} {}", crate_name, target);
+                if !versions.contains(&version) {
+                    versions.push(version);
+                }
+            }
+        }
+
+        if versions.is_empty() {
+            return Err(FluxorCliError::Manifest {
+                path: directory.join("Cargo.toml"),
+                message: "no fluxor dependency found".to_string(),
+            });
+        }
+
+        if changed == 0 {
+            println!("Already up to date ({}).", versions.join(", "));
+        } else {
+            println!(
+                "Upgraded {} in {} manifest{}. Run `cargo build` to update Cargo.lock.",
+                versions.join(", "),
+                changed,
+                if changed == 1 { "" } else { "s" }
+            );
+        }
+
+        Ok(())
+    }
+
+    /// The manifests to upgrade: the package containing `directory`, or the workspace root and members.
+    fn manifests(&self, directory: &Path) -> Result<Vec<PathBuf>> {
+        let metadata = MetadataCommand::new()
+            .current_dir(directory)
+            .no_deps()
+            .exec()
+            .map_err(|e| FluxorCliError::Manifest {
+                path: directory.join("Cargo.toml"),
+                message: e.to_string(),
+            })?;
+
+        let root_manifest = metadata.workspace_root.join("Cargo.toml").into_std_path_buf();
+        let members = metadata.workspace_packages();
+
+        if self.workspace {
+            let mut manifests = vec![root_manifest];
+            for package in members {
+                let manifest = package.manifest_path.clone().into_std_path_buf();
+                if !manifests.contains(&manifest) {
+                    manifests.push(manifest);
+                }
+            }
+            return Ok(manifests);
+        }
+
+        let directory = directory.canonicalize().map_err(|source| FluxorCliError::io(directory, source))?;
+        let package = members
+            .iter()
+            .filter(|package| package.manifest_path.parent().is_some_and(|dir| directory.starts_with(dir)))
+            .max_by_key(|package| package.manifest_path.components().count())
+            .ok_or_else(|| FluxorCliError::Manifest {
+                path: root_manifest.clone(),
+                message: "this is a virtual workspace; pass --workspace to upgrade every member".to_string(),
+            })?;
+
+        let mut manifests = vec![package.manifest_path.clone().into_std_path_buf()];
+        // Members may inherit the version from `[workspace.dependencies]`
+        if manifests[0] != root_manifest {
+            manifests.push(root_manifest);
+        }
+        Ok(manifests)
+    }
+
+    /// The version written for `crate_name` in place of its `current` requirement.
+    ///
+    /// `--to` picks the fluxor version; otherwise each crate moves to the newest release compatible
+    /// with its current requirement, or the newest release overall with `--major`.
+    fn target_version(&self, crate_name: &str, current: &str) -> Result<String> {
+        let resolver = VersionResolver::new(self.offline);
+        let invalid = || FluxorCliError::InvalidVersion {
+            crate_name: crate_name.to_string(),
+            version: self.to.to_string(),
+        };
+
+        let version = if crate_name == "fluxor" && self.to.trim() != "latest" {
+            let to = self.to.trim();
+            match Version::parse(to) {
+                // An exact version is written as given once it is known to exist
+                Ok(version) => {
+                    resolver.matching(crate_name, &VersionReq::parse(&format!("={}", version)).map_err(|_| invalid())?)?;
+                    version.to_string()
+                }
+                Err(_) => {
+                    let requirement = VersionReq::parse(to).map_err(|_| invalid())?;
+                    resolver.matching(crate_name, &requirement)?.0.to_string()
+                }
+            }
+        } else {
+            match compatible_requirement(current).filter(|_| !self.major) {
+                Some(requirement) => {
+                    // The local registry cache may lag behind crates.io
+                    let latest = if self.offline { None } else { fetch_latest_version(crate_name).ok() };
+                    match latest.filter(|latest| Version::parse(latest).is_ok_and(|latest| requirement.matches(&latest))) {
+                        Some(latest) => latest,
+                        None => resolver.matching(crate_name, &requirement)?.0.to_string(),
+                    }
+                }
+                None if !self.offline => fetch_latest_version(crate_name)?,
+                None => resolver.resolve("latest", crate_name)?,
+            }
+        };
+
+        Ok(keep_operator(current, &version))
+    }
+}
+
+/// The requirement an upgrade of the `current` requirement stays within: the caret range of its
+/// version, e.g. `^0.7.1` for `0.7.1` or `=0.7.1`. Requirements with several comparators are kept.
+///
+/// Returns `None` for requirements that accept any version, such as `*`.
+fn compatible_requirement(current: &str) -> Option<VersionReq> {
+    let requirement = VersionReq::parse(current.trim()).ok()?;
+    match requirement.comparators.as_slice() {
+        [] => None,
+        [comparator] if comparator.op == Op::Wildcard && comparator.minor.is_none() => None,
+        [comparator] => Some(VersionReq {
+            comparators: vec![Comparator {
+                op: Op::Caret,
+                ..comparator.clone()
+            }],
+        }),
+        _ => Some(requirement),
+    }
+}
+
+/// Keeps the `=` of an exact pin such as `=1.1.2`, so upgrading it does not loosen it.
+fn keep_operator(current: &str, version: &str) -> String {
+    if current.trim().starts_with('=') {
+        format!("={}", version)
+    } else {
+        version.to_string()
+    }
+}
+
+fn dependency_tables(document: &DocumentMut) -> impl Iterator<Item = &dyn TableLike> {
+    DEPENDENCY_TABLES.iter().filter_map(|path| {
+        path.iter()
+            .try_fold(document.as_item(), |item, key| item.get(key))
+            .and_then(Item::as_table_like)
+    })
+}
+
+/// The versions of `crate_name` in the dependency tables of `document`; inherited
+/// `{ workspace = true }` entries have none.
+fn current_versions(document: &DocumentMut, crate_name: &str) -> Vec<String> {
+    dependency_tables(document)
+        .filter_map(|table| {
+            let item = table.get(crate_name)?;
+            item.as_str()
+                .or_else(|| item.get("version").and_then(Item::as_str))
+                .map(str::to_string)
+        })
+        .collect()
+}
+
+/// Replaces each version of `crate_name` in the dependency tables with `target(version)`,
+/// keeping comments and formatting.
+fn set_version(document: &mut DocumentMut, crate_name: &str, target: &dyn Fn(&str) -> String) {
+    for path in DEPENDENCY_TABLES {
+        // `Item::get_mut` would insert the missing tables, so walk them as tables
+        let table = path.iter().try_fold(document.as_table_mut() as &mut dyn TableLike, |table, key| {
+            table.get_mut(key).and_then(Item::as_table_like_mut)
+        });
+        let Some(item) = table.and_then(|table| table.get_mut(crate_name)) else {
+            continue;
+        };
+
+        let value = match item {
+            Item::Value(Value::String(_)) => item.as_value_mut(),
+            _ => item
+                .as_table_like_mut()
+                .and_then(|table| table.get_mut("version"))
+                .and_then(Item::as_value_mut),
+        };
+
+        let Some(value) = value else {
+            continue;
+        };
+        let Some(version) = value.as_str().map(target) else {
+            continue;
+        };
+
+        if value.as_str() != Some(version.as_str()) {
+            let decor = value.decor().clone();
+            *value = Value::from(version);
+            *value.decor_mut() = decor;
+        }
+    }
+}
+
+/// A unified diff of two versions of a file, without context lines.
+fn diff(path: &str, old: &str, new: &str) -> String {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // Longest common subsequence table, filled from the end
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut output = format!("--- {}\n+++ {}\n", path, path);
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            i += 1;
+            j += 1;
+            continue;
+        }
+
+        let (start_old, start_new) = (i, j);
+        while i < old.len() && (j == new.len() || (old[i] != new[j] && common[i + 1][j] >= common[i][j + 1])) {
+            i += 1;
+        }
+        while j < new.len() && (i == old.len() || old[i] != new[j]) {
+            j += 1;
+        }
+
+        output.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            start_old + 1,
+            i - start_old,
+            start_new + 1,
+            j - start_new
+        ));
+        for line in &old[start_old..i] {
+            output.push_str(&format!("-{}\n", line));
+        }
+        for line in &new[start_new..j] {
+            output.push_str(&format!("+{}\n", line));
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn upgraded(manifest: &str, crate_name: &str, target: &dyn Fn(&str) -> String) -> String {
+        let mut document = manifest.parse::<DocumentMut>().unwrap();
+        set_version(&mut document, crate_name, target);
+        document.to_string()
+    }
+
+    #[test]
+    fn set_version_keeps_inline_tables_and_comments() {
+        let manifest = r#"[dependencies]
+fluxor = { version = "1.1.2", default-features = false } # web framework
+serde = "1"
+"#;
+
+        assert_eq!(
+            upgraded(manifest, "fluxor", &|_| "1.2.0".to_string()),
+            r#"[dependencies]
+fluxor = { version = "1.2.0", default-features = false } # web framework
+serde = "1"
+"#
+        );
+    }
+
+    #[test]
+    fn set_version_upgrades_the_workspace_instead_of_inheriting_members() {
+        let root = r#"[workspace]
+members = ["app"]
+
+[workspace.dependencies]
+fluxor = "1.1.2"
+"#;
+        let member = r#"[package]
+name = "app"
+
+[dependencies]
+fluxor = { workspace = true }
+"#;
+
+        assert_eq!(current_versions(&root.parse().unwrap(), "fluxor"), ["1.1.2"]);
+        assert!(current_versions(&member.parse().unwrap(), "fluxor").is_empty());
+        assert_eq!(upgraded(root, "fluxor", &|_| "1.2.0".to_string()), root.replace("1.1.2", "1.2.0"));
+        assert_eq!(upgraded(member, "fluxor", &|_| "1.2.0".to_string()), member);
+    }
+
+    #[test]
+    fn set_version_does_not_add_missing_dependencies() {
+        let manifest = "[dependencies]\nfluxor = \"1.1.2\"\n";
+
+        assert_eq!(upgraded(manifest, "crator", &|_| "0.7.1".to_string()), manifest);
+    }
+
+    #[test]
+    fn exact_pins_stay_exact() {
+        let manifest = "[dependencies]\nfluxor = \"=1.1.2\"\n";
+
+        assert_eq!(
+            upgraded(manifest, "fluxor", &|current| keep_operator(current, "1.2.0")),
+            "[dependencies]\nfluxor = \"=1.2.0\"\n"
+        );
+        assert_eq!(keep_operator("1.1.2", "1.2.0"), "1.2.0");
+    }
+
+    #[test]
+    fn upgrades_stay_within_the_current_major() {
+        let compatible = |current: &str| compatible_requirement(current).map(|requirement| requirement.to_string());
+
+        assert_eq!(compatible("0.7.1").as_deref(), Some("^0.7.1"));
+        assert_eq!(compatible("=1.1.2").as_deref(), Some("^1.1.2"));
+        assert_eq!(compatible("~1.1").as_deref(), Some("^1.1"));
+        assert_eq!(compatible(">=1, <3").as_deref(), Some(">=1, <3"));
+        assert_eq!(compatible("*"), None);
+
+        let requirement = compatible_requirement("0.7.1").unwrap();
+        assert!(requirement.matches(&Version::new(0, 7, 3)));
+        assert!(!requirement.matches(&Version::new(1, 2, 0)));
+    }
+
+    #[test]
+    fn diff_prints_changed_lines_only() {
+        let old = "[dependencies]\ncrator = \"0.7.1\"\nfluxor = \"1.1.2\"\n";
+        let new = "[dependencies]\ncrator = \"0.7.1\"\nfluxor = \"1.2.0\"\n";
+
+        assert_eq!(
+            diff("Cargo.toml", old, new),
+            "--- Cargo.toml\n+++ Cargo.toml\n@@ -3,1 +3,1 @@\n-fluxor = \"1.1.2\"\n+fluxor = \"1.2.0\"\n"
+        );
+        assert_eq!(diff("Cargo.toml", old, old), "--- Cargo.toml\n+++ Cargo.toml\n");
+    }
+
+    #[test]
+    fn diff_handles_added_and_removed_lines() {
+        assert_eq!(diff("a", "x\ny\n", "x\n"), "--- a\n+++ a\n@@ -2,1 +2,0 @@\n-y\n");
+        assert_eq!(diff("a", "x\n", "x\ny\n"), "--- a\n+++ a\n@@ -2,0 +2,1 @@\n+y\n");
+    }
+}
@@ -0,0 +1,400 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command as ProcessCommand;
+
+use semver::{Version, VersionReq};
+
+use crate::error::{FluxorCliError, Result};
+
+/// Where a resolved crate version was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionSource {
+    /// The local registry index cache under `$CARGO_HOME/registry/index`.
+    IndexCache,
+    /// The downloaded `.crate` files under `$CARGO_HOME/registry/cache`.
+    CrateCache,
+    /// `cargo search` against the crates.io registry.
+    Network,
+}
+
+/// A requested dependency version: `latest` or a semver requirement.
+///
+/// An exact version such as `1.1.2` is a requirement too, with Cargo's caret semantics.
+///
+/// # Examples
+///
+/// ```rust
+/// use fluxor_cli::version::VersionSpec;
+///
+/// assert_eq!(VersionSpec::parse("fluxor", "latest").unwrap(), VersionSpec::Latest);
+/// assert!(matches!(VersionSpec::parse("fluxor", ">=1, <2"), Ok(VersionSpec::Requirement(_))));
+/// assert!(VersionSpec::parse("fluxor", "banana").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VersionSpec {
+    Latest,
+    Requirement(VersionReq),
+}
+
+impl VersionSpec {
+    /// Parses the version requested for `crate_name`.
+    pub fn parse(crate_name: &str, version: &str) -> Result<Self> {
+        let version = version.trim();
+
+        if version == "latest" {
+            return Ok(VersionSpec::Latest);
+        }
+
+        VersionReq::parse(version)
+            .map(VersionSpec::Requirement)
+            .map_err(|_| FluxorCliError::InvalidVersion {
+                crate_name: crate_name.to_string(),
+                version: version.to_string(),
+            })
+    }
+}
+
+/// Resolves the latest version of a crate.
+///
+/// Sources are tried in order: the local registry index cache, the downloaded `.crate`
+/// files and, unless the resolver is offline, a network lookup with `cargo search`.
+/// The first source that knows the crate wins, so air-gapped hosts with a populated
+/// Cargo home never touch the network.
+///
+/// Pre-releases are skipped unless [`VersionResolver::allow_prerelease`] is set.
+#[derive(Debug, Clone, Default)]
+pub struct VersionResolver {
+    cargo_home: Option<PathBuf>,
+    offline: bool,
+    allow_prerelease: bool,
+}
+
+impl VersionResolver {
+    /// Creates a resolver using the Cargo home of the current user.
+    pub fn new(offline: bool) -> Self {
+        VersionResolver {
+            cargo_home: cargo_home(),
+            offline,
+            allow_prerelease: false,
+        }
+    }
+
+    /// Also consider pre-release versions such as `2.0.0-beta.1` when resolving `latest`.
+    pub fn allow_prerelease(mut self, allow_prerelease: bool) -> Self {
+        self.allow_prerelease = allow_prerelease;
+        self
+    }
+
+    /// Uses `cargo_home` instead of `$CARGO_HOME` (or `~/.cargo`).
+    pub fn with_cargo_home(mut self, cargo_home: impl Into<PathBuf>) -> Self {
+        self.cargo_home = Some(cargo_home.into());
+        self
+    }
+
+    /// Whether the network lookup is disabled.
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Resolves `version` to the requirement written to Cargo.toml.
+    ///
+    /// `latest` becomes the latest version of `crate_name`; any other requirement is kept
+    /// as written once a published version is known to satisfy it.
+    pub fn resolve(&self, version: &str, crate_name: &str) -> Result<String> {
+        match VersionSpec::parse(crate_name, version)? {
+            VersionSpec::Latest => self.latest(crate_name).map(|(version, _)| version),
+            VersionSpec::Requirement(requirement) => {
+                self.matching(crate_name, &requirement)?;
+                Ok(version.trim().to_string())
+            }
+        }
+    }
+
+    /// Finds the highest version of `crate_name` that satisfies `requirement`.
+    ///
+    /// The local caches are searched first; if none of their versions match and the resolver
+    /// is online, the index entry of the crate is refreshed with `cargo info` and searched again.
+    pub fn matching(&self, crate_name: &str, requirement: &VersionReq) -> Result<(Version, VersionSource)> {
+        let highest_match = |versions: Vec<Version>| versions.into_iter().filter(|version| requirement.matches(version)).max();
+
+        if let Some(version) = highest_match(self.index_cache_versions(crate_name)) {
+            return Ok((version, VersionSource::IndexCache));
+        }
+
+        if let Some(version) = highest_match(self.crate_cache_versions(crate_name)) {
+            return Ok((version, VersionSource::CrateCache));
+        }
+
+        let unsatisfiable = |message: String| FluxorCliError::VersionLookup {
+            crate_name: crate_name.to_string(),
+            message,
+        };
+
+        if self.offline {
+            return Err(unsatisfiable(format!(
+                "no cached version matches `{}` and --offline forbids a network lookup",
+                requirement
+            )));
+        }
+
+        refresh_index(crate_name)?;
+
+        highest_match(self.index_cache_versions(crate_name))
+            .map(|version| (version, VersionSource::Network))
+            .ok_or_else(|| unsatisfiable(format!("no published version matches `{}`", requirement)))
+    }
+
+    /// Finds the latest version of `crate_name` and the source it came from.
+    pub fn latest(&self, crate_name: &str) -> Result<(String, VersionSource)> {
+        if let Some(version) = self.highest(self.index_cache_versions(crate_name)) {
+            return Ok((version.to_string(), VersionSource::IndexCache));
+        }
+
+        if let Some(version) = self.highest(self.crate_cache_versions(crate_name)) {
+            return Ok((version.to_string(), VersionSource::CrateCache));
+        }
+
+        if self.offline {
+            let searched = self
+                .cargo_home
+                .as_ref()
+                .map(|home| home.join("registry").display().to_string())
+                .unwrap_or_else(|| "the Cargo home (not found)".to_string());
+
+            return Err(FluxorCliError::VersionLookup {
+                crate_name: crate_name.to_string(),
+                message: format!(
+                    "no cached version found in {} and --offline forbids a network lookup; pass an explicit --version",
+                    searched
+                ),
+            });
+        }
+
+        let versions = search_versions(crate_name)?;
+        let version = self.highest(versions.clone()).ok_or_else(|| FluxorCliError::VersionLookup {
+            crate_name: crate_name.to_string(),
+            message: if versions.is_empty() {
+                format!("crate '{}' not found", crate_name)
+            } else {
+                "the latest published version is a pre-release; pass --allow-prerelease or an explicit --version".to_string()
+            },
+        })?;
+
+        Ok((version.to_string(), VersionSource::Network))
+    }
+
+    fn highest(&self, versions: Vec<Version>) -> Option<Version> {
+        versions
+            .into_iter()
+            .filter(|version| self.allow_prerelease || version.pre.is_empty())
+            .max()
+    }
+
+    /// Every non-yanked version of `crate_name` recorded in the local registry index caches.
+    pub fn index_cache_versions(&self, crate_name: &str) -> Vec<Version> {
+        let Some(cargo_home) = &self.cargo_home else {
+            return Vec::new();
+        };
+
+        let relative = index_path(crate_name);
+        let mut versions = Vec::new();
+
+        for registry in registry_dirs(&cargo_home.join("registry/index")) {
+            if let Ok(bytes) = fs::read(registry.join(".cache").join(&relative)) {
+                versions.extend(parse_index_cache(&bytes));
+            }
+        }
+
+        versions
+    }
+
+    /// Every version of `crate_name` whose `.crate` file has been downloaded.
+    pub fn crate_cache_versions(&self, crate_name: &str) -> Vec<Version> {
+        let Some(cargo_home) = &self.cargo_home else {
+            return Vec::new();
+        };
+
+        let prefix = format!("{}-", crate_name);
+        let mut versions = Vec::new();
+
+        for registry in registry_dirs(&cargo_home.join("registry/cache")) {
+            let Ok(entries) = fs::read_dir(registry) else {
+                continue;
+            };
+
+            for entry in entries.flatten() {
+                let file_name = entry.file_name().to_string_lossy().into_owned();
+                let version = file_name
+                    .strip_prefix(&prefix)
+                    .and_then(|rest| rest.strip_suffix(".crate"))
+                    .and_then(|version| Version::parse(version).ok());
+
+                versions.extend(version);
+            }
+        }
+
+        versions
+    }
+}
+
+/// Fetches the latest stable version of `crate_name` from crates.io with `cargo search`.
+pub fn fetch_latest_version(crate_name: &str) -> Result<String> {
+    VersionResolver::default().latest(crate_name).map(|(version, _)| version)
+}
+
+/// Runs `cargo search` and returns the versions it lists for exactly `crate_name`.
+fn search_versions(crate_name: &str) -> Result<Vec<Version>> {
+    let version_lookup = |message: String| FluxorCliError::VersionLookup {
+        crate_name: crate_name.to_string(),
+        message,
+    };
+
+    let output = ProcessCommand::new("cargo")
+        .arg("search")
+        .arg(crate_name)
+        .output()
+        .map_err(|e| version_lookup(format!("failed to run `cargo search`: {}", e)))?;
+
+    if !output.status.success() {
+        return Err(version_lookup(String::from_utf8_lossy(&output.stderr).trim().to_string()));
+    }
+
+    Ok(parse_search_output(&String::from_utf8_lossy(&output.stdout), crate_name))
+}
+
+/// Refreshes the local index cache entry of `crate_name` by running `cargo info`.
+fn refresh_index(crate_name: &str) -> Result<()> {
+    let output = ProcessCommand::new("cargo")
+        .arg("info")
+        .arg(crate_name)
+        .output()
+        .map_err(|e| FluxorCliError::VersionLookup {
+            crate_name: crate_name.to_string(),
+            message: format!("failed to run `cargo info`: {}", e),
+        })?;
+
+    if !output.status.success() {
+        return Err(FluxorCliError::VersionLookup {
+            crate_name: crate_name.to_string(),
+            message: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Parses `cargo search` output, returning the versions listed for exactly `crate_name`.
+///
+/// Each result line has the form `name = "version"    # description`; lines for other crates
+/// whose name merely contains `crate_name` are ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use fluxor_cli::version::parse_search_output;
+///
+/// let output = "fluxor_cli = \"1.1.2\"    # Fluxor CLI\nfluxor = \"2.0.0-rc.1\"    # Fluxor\n";
+/// let versions = parse_search_output(output, "fluxor");
+///
+/// assert_eq!(versions.len(), 1);
+/// assert_eq!(versions[0].to_string(), "2.0.0-rc.1");
+/// ```
+pub fn parse_search_output(output: &str, crate_name: &str) -> Vec<Version> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (name, rest) = line.split_once('=')?;
+            if !same_crate_name(name.trim(), crate_name) {
+                return None;
+            }
+
+            let version = rest.trim_start().strip_prefix('"')?.split('"').next()?;
+            Version::parse(version).ok()
+        })
+        .collect()
+}
+
+/// The Cargo home directory: `$CARGO_HOME`, or `.cargo` in the user's home directory.
+pub fn cargo_home() -> Option<PathBuf> {
+    if let Some(home) = env::var_os("CARGO_HOME") {
+        return Some(PathBuf::from(home));
+    }
+
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(".cargo"))
+}
+
+/// The path of a crate inside a registry index, e.g. `fl/ux/fluxor` or `3/s/syn`.
+///
+/// # Examples
+///
+/// ```rust
+/// use fluxor_cli::version::index_path;
+///
+/// assert_eq!(index_path("fluxor"), "fl/ux/fluxor");
+/// assert_eq!(index_path("syn"), "3/s/syn");
+/// assert_eq!(index_path("cc"), "2/cc");
+/// assert_eq!(index_path("Crator"), "cr/at/crator");
+/// ```
+pub fn index_path(crate_name: &str) -> String {
+    let name = crate_name.to_lowercase();
+
+    match name.len() {
+        1 => format!("1/{}", name),
+        2 => format!("2/{}", name),
+        3 => format!("3/{}/{}", &name[..1], name),
+        _ => format!("{}/{}/{}", &name[..2], &name[2..4], name),
+    }
+}
+
+/// Parses a registry index cache file, returning every version that is not yanked.
+///
+/// The file starts with a one byte cache version, a four byte index version and the
+/// NUL-terminated index revision, followed by NUL-separated pairs of version and JSON entry.
+fn parse_index_cache(bytes: &[u8]) -> Vec<Version> {
+    let Some(body) = bytes.get(5..) else {
+        return Vec::new();
+    };
+
+    let mut fields = body.split(|&byte| byte == 0).skip(1);
+    let mut versions = Vec::new();
+
+    while let (Some(version), Some(entry)) = (fields.next(), fields.next()) {
+        let Ok(entry) = serde_json::from_slice::<serde_json::Value>(entry) else {
+            continue;
+        };
+
+        if entry.get("yanked").and_then(|yanked| yanked.as_bool()).unwrap_or(false) {
+            continue;
+        }
+
+        if let Ok(version) = Version::parse(&String::from_utf8_lossy(version)) {
+            versions.push(version);
+        }
+    }
+
+    versions
+}
+
+/// crates.io treats `-` and `_` as well as ASCII case as equivalent in crate names.
+fn same_crate_name(a: &str, b: &str) -> bool {
+    a.len() == b.len() && a.bytes().zip(b.bytes()).all(|(a, b)| normalize(a) == normalize(b))
+}
+
+fn normalize(byte: u8) -> u8 {
+    if byte == b'-' { b'_' } else { byte.to_ascii_lowercase() }
+}
+
+fn registry_dirs(path: &Path) -> Vec<PathBuf> {
+    fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.is_dir())
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,39 @@
+window.onload = () => {
+  const fluxor = document.getElementById('fluxor');
+  let expanding = true;
+  const minSize = 100;
+  const maxSize = 300;
+  let size = minSize;
+
+  setInterval(() => {
+    if (expanding) {
+      size += 10;
+      if (size >= maxSize) {
+        expanding = false;
+      }
+    } else {
+      size -= 10;
+      if (size <= minSize) {
+        expanding = true;
+      }
+    }
+    fluxor.style.width = size + 'px';
+    fluxor.style.height = size + 'px';
+  }, 100);
+
+  const textContainer = document.getElementById('animatedText');
+  const text = textContainer.textContent;
+  textContainer.textContent = '';
+
+  for (let i = 0; i < text.length; i++) {
+    const span = document.createElement('span');
+    span.textContent = text[i];
+    span.style.animationDelay = `${i * 0.2}s`;
+    textContainer.appendChild(span);
+  }
+
+  const spans = textContainer.querySelectorAll('span');
+  spans.forEach(span => {
+    span.style.animationName = 'fadeIn';
+  });
+};
\ No newline at end of file
@@ -0,0 +1,43 @@
+use fluxor::prelude::*;
+
+const HTML: &str = r#"<!DOCTYPE html>
+<html lang="en">
+    <head>
+        <meta charset="UTF-8" />
+        <meta name="viewport" content="width=device-width, initial-scale=1.0" />
+        <link rel="stylesheet" href="/css/styles.css" />
+        <title>Assets Example</title>
+    </head>
+    <body>
+        <h1>
+            <span class="hand">👋</span>
+            <span class="animated-text" id="animatedText">&ensp;from&nbsp;the&nbsp;fluxor&nbsp;framework&ensp;</span>
+        </h1>
+        <img id="fluxor" src="/img/fluxor.svg" alt="Fluxor logo" />
+        <script src="/js/script.js"></script>
+    </body>
+</html>"#;
+
+fn home(_req: Req, _params: Params) -> Reply {
+    boxed(async {
+        Ok(Response::builder()
+            .header("Content-Type", "text/html; charset=UTF-8")
+            .body(Body::from(HTML))
+            .unwrap())
+    })
+}
+
+#[tokio::main]
+async fn main() {
+    let mut app = Fluxor::new();        // Initialize the application.
+    
+    // Configure the application
+    // let static_dir = "public".to_string();         // Retrieve the static directory "public"
+    // let static_dir = "src/assets".to_string();     // Retrieve the static directory "src/assets"
+    let static_dir = "assets".to_string();         // Retrieve the static directory "assets"
+
+    app.set_dir(static_dir);            // Set directory for static files
+
+    app.route(GET, "/", home);          // Set the home route.
+    app.run("127.0.0.1", "8080").await; // Start the HTTP server (host, port).
+}
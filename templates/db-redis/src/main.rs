@@ -0,0 +1,32 @@
+use fluxor::prelude::*;
+
+fn index(_req: Req, _params: Params) -> Reply {
+    boxed(async move {
+        let json_response = format!(
+            r#"{{"host": "{}", "port": "{}"}}"#,
+            env_var("HOST", "0.0.0.0"), // Load HOST from environment; default to "0.0.0.0" if not set or not in .env
+            env_var("PORT", "10000")    // Load PORT from environment; default to "10000" if not set or not in .env
+        );
+        
+        Ok(Response::builder()
+            .header("Content-Type", "application/json")
+            .body(Body::from(json_response))
+            .unwrap())
+    })
+}
+
+#[tokio::main]
+async fn main() {
+    load_dotenv(); // Load environment variables from the .env file
+
+    let mut app = Fluxor::new();        // Initialize the application
+
+    app.route(GET, "/", index);         // Set the index route
+
+    // Get server configuration from environment variables
+    let port = env_var("PORT", "10000");   // PORT is loaded from .env if available; defaults to "10000" otherwise
+
+    let host = env_var("HOST", "0.0.0.0"); // HOST is loaded from .env if available; defaults to "0.0.0.0" otherwise
+
+    app.run(&host, &port).await; // Start the HTTP server with specified host and port
+}
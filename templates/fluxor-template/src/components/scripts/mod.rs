@@ -0,0 +1,3 @@
+mod sw_register;
+
+pub use sw_register::SW_REGISTER;
\ No newline at end of file
@@ -0,0 +1 @@
+// db here..
\ No newline at end of file
@@ -0,0 +1 @@
+// data science and computing applications here..
\ No newline at end of file
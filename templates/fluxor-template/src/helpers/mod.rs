@@ -0,0 +1 @@
+// helpers here..
\ No newline at end of file
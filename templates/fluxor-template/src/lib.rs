@@ -0,0 +1,13 @@
+pub mod components;
+pub mod routes;
+// pub mod db;
+// pub mod ds;
+// pub mod helpers;
+// pub mod validators;
+
+pub use components::*;
+pub use routes::*;
+// pub use db::*;
+// pub use ds::*;
+// pub use helpers::*;
+// pub use validators::*;
\ No newline at end of file
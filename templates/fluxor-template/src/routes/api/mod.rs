@@ -0,0 +1,3 @@
+mod greeting;
+
+pub use greeting::hello_world;
\ No newline at end of file
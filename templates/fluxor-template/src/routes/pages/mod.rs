@@ -0,0 +1,5 @@
+mod home;
+mod analytics;
+
+pub use home::home_page;
+pub use analytics::analytics_page;
\ No newline at end of file
@@ -0,0 +1 @@
+// validators here..
\ No newline at end of file
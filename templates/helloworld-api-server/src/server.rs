@@ -0,0 +1,25 @@
+use fluxor::prelude::*;
+
+fn hello(_req: Req, _params: Params) -> Reply {
+    boxed(async move {
+       let json_response = do_json!(
+        r#"
+            {"message": "{{waving_hand_emoji}} Hello, World!"}
+        "#, 
+        waving_hand_emoji = "👋"
+    );
+        
+        Ok(Response::builder()
+            .header("Content-Type", "application/json")
+            .body(Body::from(json_response))
+            .unwrap())
+    })
+}
+
+#[tokio::main]
+async fn main() {
+    let mut server = Fluxor::new();                         // Initialize the application.
+    server.route(POST, "/", hello);                         // Set the route (method, path, handler).
+    server.route(GET, "/http-client", serve_http_client);   // A simple http client to test your application.
+    server.run("127.0.0.1", "8080").await;                  // Start the HTTP server (host, port).
+}
@@ -0,0 +1,20 @@
+use fluxor::prelude::*;
+
+fn hello(_req: Req, _params: Params) -> Reply {
+    boxed(async move {
+       let json_response = format!(r#"{{"message": "👋 Hello, World!"}}"#);
+        
+        Ok(Response::builder()
+            .header("Content-Type", "application/json")
+            .body(Body::from(json_response))
+            .unwrap())
+    })
+}
+
+#[tokio::main]
+async fn main() {
+    let mut app = Fluxor::new();                        // Initialize the application.
+    app.route(GET, "/", hello);                         // Set the route (method, path, handler).
+    app.route(GET, "/http-client", serve_http_client);  // A simple http client to test your application.
+    app.run("127.0.0.1", "8080").await;                 // Start the HTTP server (host, port).
+}
@@ -0,0 +1,17 @@
+use fluxor::prelude::*;
+
+fn hello(_req: Req, _params: Params) -> Reply {
+    boxed(async {
+        Ok(Response::builder()
+            .header("Content-Type", "text/html; charset=UTF-8")
+            .body(Body::from("<h1>👋 Hello, World!</h1>"))
+            .unwrap())
+    })
+}
+
+#[tokio::main]
+async fn main() {
+    let mut app = Fluxor::new();        // Initialize the application.
+    app.route(GET, "/", hello);         // Set the route (method, path, handler).
+    app.run("127.0.0.1", "8080").await; // Start the HTTP server (host, port).
+}
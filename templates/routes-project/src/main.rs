@@ -0,0 +1,11 @@
+use fluxor::prelude::*;
+
+// project name / routes / setup_routes function
+use {{cratename}}::routes::setup_routes;
+
+#[tokio::main]
+async fn main() {
+    let mut app = Fluxor::new(); // Initialize the application.
+    setup_routes(&mut app);              // Setup HTTP routes.
+    app.run("127.0.0.1", "8080").await;  // Start the HTTP server (host, port).
+}
@@ -0,0 +1,48 @@
+use fluxor::prelude::*;
+
+const JSON_TEMPLATE: &str = r##"{
+  "id": "{{dynamic_id}}",
+  "dynamic_route": "by_{{approach}}",
+  "message": "The id value was retrieved using the {{approach}} approach."
+}"##;
+
+pub fn dynamic_route_by_request(req: Req, _params: Params) -> Reply {
+    // app.route(GET, "/api/req/<id>", dynamic_route_by_request);
+    // Clone the path string
+    let path = req.uri().path().to_string();
+
+    boxed(async move {
+        // Use the cloned string inside async block
+        let id_value = path.trim_start_matches("/api/req/");
+        
+        let json_response = do_json!(
+            JSON_TEMPLATE,
+            dynamic_id = id_value,
+            approach = "request"
+        );
+
+        Ok(Response::builder()
+            .header("Content-Type", "application/json")
+            .body(Body::from(json_response))
+            .unwrap())
+    })
+}
+
+pub fn dynamic_route_by_params(_req: Req, params: Params) -> Reply {
+    // app.route(GET, "/api/params/<id>", dynamic_route_by_params);
+    // Retrieve the "id" parameter from params.extra
+    let id_value = params.extra.get("id").cloned().unwrap_or_default();
+
+    boxed(async move {
+        let json_response = do_json!(
+            JSON_TEMPLATE,
+            dynamic_id = &id_value,
+            approach = "params"
+        );
+
+        Ok(Response::builder()
+            .header("Content-Type", "application/json")
+            .body(Body::from(json_response))
+            .unwrap())
+    })
+}
\ No newline at end of file
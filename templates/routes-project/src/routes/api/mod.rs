@@ -0,0 +1,2 @@
+pub mod msg;
+pub mod dynamic_route;
\ No newline at end of file
@@ -0,0 +1,12 @@
+use fluxor::prelude::*;
+
+pub fn api_message(_req: Req, _params: Params) -> Reply {
+    boxed(async move {
+       let json_response = format!(r#"{{"message": "👋 Hello, World!"}}"#);
+        
+        Ok(Response::builder()
+            .header("Content-Type", "application/json")
+            .body(Body::from(json_response))
+            .unwrap())
+    })
+}
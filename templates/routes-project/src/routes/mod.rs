@@ -0,0 +1,15 @@
+use fluxor::prelude::*;
+
+// routes modules
+mod pages;
+mod api;
+
+// Define the function to set up routes
+pub fn setup_routes(app: &mut Fluxor) {
+    app.route(GET, "/", pages::home::home);             // Home route
+    app.route(GET, "/about", pages::about::about);      // About route.
+    app.route(POST, "/api/msg", api::msg::api_message); // The api message route.
+    app.route(GET, "/api/req/<id>", api::dynamic_route::dynamic_route_by_request);      // Request approach
+    app.route(GET, "/api/params/<id>", api::dynamic_route::dynamic_route_by_params);    // Params approach
+    app.route(GET, "/http-client", serve_http_client);  // A simple http client to test your application.
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+use fluxor::prelude::*;
+
+pub fn about(_req: Req, _params: Params) -> Reply {
+    boxed(async {
+        Ok(Response::builder()
+            .header("Content-Type", "text/html; charset=UTF-8")
+            .body(Body::from("
+            <a href='/'>Home</a> | <a href='/about'>About</a>
+            <hr>
+            <h1>About Page</h1>
+            "))
+            .unwrap())
+    })
+}
\ No newline at end of file
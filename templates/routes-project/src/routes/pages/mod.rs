@@ -0,0 +1,2 @@
+pub mod home;
+pub mod about;
\ No newline at end of file
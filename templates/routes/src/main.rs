@@ -0,0 +1,53 @@
+use fluxor::prelude::*;
+
+fn home(_req: Req, _params: Params) -> Reply {
+    boxed(async {
+        Ok(Response::builder()
+            .header("Content-Type", "text/html; charset=UTF-8")
+            .body(Body::from("
+            <a href='/'>Home</a> | <a href='/about'>About</a>
+            <hr>
+            <h1>Home Page</h1>
+            "))
+            .unwrap())
+    })
+}
+
+fn about(_req: Req, _params: Params) -> Reply {
+    boxed(async {
+        Ok(Response::builder()
+            .header("Content-Type", "text/html; charset=UTF-8")
+            .body(Body::from("
+            <a href='/'>Home</a> | <a href='/about'>About</a>
+            <hr>
+            <h1>About Page</h1>
+            "))
+            .unwrap())
+    })
+}
+
+fn api_message(_req: Req, _params: Params) -> Reply {
+    boxed(async move {
+       let json_response = format!(r#"{{"message": "👋 Hello, World!"}}"#);
+        
+        Ok(Response::builder()
+            .header("Content-Type", "application/json")
+            .body(Body::from(json_response))
+            .unwrap())
+    })
+}
+
+// Define the function to set up routes
+fn setup_routes(app: &mut Fluxor) {
+    app.route(GET, "/", home);                // Set the home route.
+    app.route(GET, "/about", about);          // Set the about route.
+    app.route(POST, "/api/msg", api_message); // Set the api message route.
+    app.route(GET, "/http-client", serve_http_client); // A simple http client to test your application.
+}
+
+#[tokio::main]
+async fn main() {
+    let mut app = Fluxor::new();        // Initialize the application.
+    setup_routes(&mut app);             // Setup HTTP routes.
+    app.run("127.0.0.1", "8080").await; // Start the HTTP server (host, port).
+}
@@ -0,0 +1,61 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use fluxor_cli::{EXAMPLES, NewProject};
+
+/// Generates every example with its default versions into one workspace and checks that it compiles.
+#[test]
+fn examples_compile() {
+    let workspace = Path::new(env!("CARGO_TARGET_TMPDIR")).join("examples");
+    if workspace.exists() {
+        fs::remove_dir_all(&workspace).unwrap();
+    }
+    fs::create_dir_all(&workspace).unwrap();
+
+    let mut members = Vec::new();
+    for example in EXAMPLES {
+        let name = format!("example-{}", example.name());
+        let project = NewProject {
+            name: &name,
+            version: "latest",
+            example: example.name(),
+            offline: false,
+            allow_prerelease: false,
+            dependencies: &[],
+            template: None,
+            values: &[],
+            values_file: None,
+            no_hooks: true,
+            yes: false,
+        };
+
+        let sink = project.preview().unwrap();
+        let package_name = name.replace('-', "_");
+        let mut files: Vec<PathBuf> = sink.files().keys().map(|path| path.strip_prefix(&package_name).unwrap().to_path_buf()).collect();
+        let expected: Vec<PathBuf> = example.files().iter().map(PathBuf::from).collect();
+        files.sort();
+        assert_eq!(files, expected, "files of the '{}' example", example.name());
+
+        for (path, contents) in sink.files() {
+            let path = workspace.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+        members.push(format!("{:?}", package_name));
+    }
+
+    fs::write(
+        workspace.join("Cargo.toml"),
+        format!("[workspace]\nresolver = \"3\"\nmembers = [{}]\n", members.join(", ")),
+    )
+    .unwrap();
+
+    let status = Command::new(env!("CARGO"))
+        .current_dir(&workspace)
+        .args(["check", "--workspace", "--quiet"])
+        .env("CARGO_TARGET_DIR", Path::new(env!("CARGO_TARGET_TMPDIR")).join("examples-target"))
+        .status()
+        .unwrap();
+    assert!(status.success(), "the examples do not compile");
+}
@@ -0,0 +1,119 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// Runs `git` in `dir`, panicking when it fails.
+fn git(dir: &Path, args: &[&str]) {
+    let output = Command::new("git")
+        .current_dir(dir)
+        .args(["-c", "user.name=fluxor", "-c", "user.email=fluxor@example.com", "-c", "init.defaultBranch=main"])
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "git {:?}: {}", args, String::from_utf8_lossy(&output.stderr));
+}
+
+/// Runs `fluxor` in `dir` with its template cache under `root`.
+fn fluxor(root: &Path, dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_fluxor"))
+        .current_dir(dir)
+        .env("XDG_CACHE_HOME", root.join("cache"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+/// Creates a bare repository holding a template, tagged `v1`, and the work tree it is pushed from.
+///
+/// The template has no dependencies, so no crate version is looked up and the tests run offline.
+fn template_repository(root: &Path) -> (PathBuf, PathBuf) {
+    let work = root.join("work");
+    fs::create_dir_all(work.join("src")).unwrap();
+    fs::write(
+        work.join("fluxor-template.toml"),
+        "[template]\nname = \"starter\"\ndependencies = []\n",
+    )
+    .unwrap();
+    fs::write(
+        work.join("Cargo.toml"),
+        "[package]\nname = \"{{cratename}}\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
+    )
+    .unwrap();
+    fs::write(work.join("src/main.rs"), "fn main() {}\n").unwrap();
+    git(&work, &["init", "--quiet"]);
+    git(&work, &["add", "."]);
+    git(&work, &["commit", "--quiet", "-m", "v1"]);
+    git(&work, &["tag", "v1"]);
+
+    let bare = root.join("starter.git");
+    git(root, &["clone", "--quiet", "--bare", "work", "starter.git"]);
+    git(&work, &["remote", "add", "origin", &bare.to_string_lossy()]);
+
+    (work, bare)
+}
+
+#[test]
+fn git_templates_are_cloned_cached_and_updated() {
+    let root = Path::new(env!("CARGO_TARGET_TMPDIR")).join("git-templates");
+    if root.exists() {
+        fs::remove_dir_all(&root).unwrap();
+    }
+    fs::create_dir_all(&root).unwrap();
+    let (work, bare) = template_repository(&root);
+    let url = format!("git+file://{}", bare.display());
+    let tagged = format!("{}#v1", url);
+
+    // The first run clones the repository into the cache
+    let output = fluxor(&root, &root, &["new", "first", "--template", &tagged, "--no-hooks"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Cloning"));
+    assert!(fs::read_to_string(root.join("first/Cargo.toml")).unwrap().contains("name = \"first\""));
+    let clones: Vec<_> = fs::read_dir(root.join("cache/fluxor/templates")).unwrap().collect();
+    assert_eq!(clones.len(), 1);
+
+    // The cached clone is reused, even offline
+    let output = fluxor(&root, &root, &["new", "second", "--template", &tagged, "--offline", "--no-hooks"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("Cloning"));
+
+    // New commits are only seen after `fluxor templates update`
+    fs::write(work.join("src/lib.rs"), "pub fn added() {}\n").unwrap();
+    git(&work, &["add", "."]);
+    git(&work, &["commit", "--quiet", "-m", "v2"]);
+    git(&work, &["push", "--quiet", "origin", "main"]);
+
+    let output = fluxor(&root, &root, &["new", "third", "--template", &url, "--offline", "--no-hooks"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(!root.join("third/src/lib.rs").exists());
+
+    let output = fluxor(&root, &root, &["templates", "update"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let output = fluxor(&root, &root, &["new", "fourth", "--template", &url, "--offline", "--no-hooks"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(root.join("fourth/src/lib.rs").exists());
+
+    // The tag still checks out the first commit
+    let output = fluxor(&root, &root, &["new", "fifth", "--template", &tagged, "--offline", "--no-hooks"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(!root.join("fifth/src/lib.rs").exists());
+}
+
+#[test]
+fn git_template_urls_are_not_read_as_options() {
+    let root = Path::new(env!("CARGO_TARGET_TMPDIR")).join("git-template-options");
+    if root.exists() {
+        fs::remove_dir_all(&root).unwrap();
+    }
+    fs::create_dir_all(&root).unwrap();
+    let marker = root.join("injected");
+
+    let template = format!("git+--upload-pack=touch {}", marker.display());
+    let output = fluxor(&root, &root, &["new", "app", "--template", &template, "--no-hooks"]);
+    assert_eq!(output.status.code(), Some(18));
+    assert!(!marker.exists());
+
+    let output = fluxor(&root, &root, &["new", "app", "--template", "git+file:///srv/starter.git#--output=x", "--no-hooks"]);
+    assert_eq!(output.status.code(), Some(18));
+    assert!(!root.join("app").exists());
+}
@@ -0,0 +1,87 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+
+/// A template without dependencies, so no crate version is looked up, whose hooks are `hooks`.
+fn template(root: &Path, hooks: &str) -> PathBuf {
+    let dir = root.join("template");
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::write(dir.join("fluxor-template.toml"), format!("[template]\ndependencies = []\nhooks = {}\n", hooks)).unwrap();
+    fs::write(dir.join("Cargo.toml"), "[package]\nname = \"{{cratename}}\"\nversion = \"0.1.0\"\nedition = \"2024\"\n").unwrap();
+    fs::write(dir.join("src/main.rs"), "fn main() {}\n").unwrap();
+    fs::write(dir.join(".env.example"), "PORT=8080\n").unwrap();
+    dir
+}
+
+/// Runs `fluxor new` in `root` without a terminal.
+fn fluxor_new(root: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_fluxor"))
+        .current_dir(root)
+        .arg("new")
+        .args(args)
+        .stdin(Stdio::null())
+        .output()
+        .unwrap()
+}
+
+fn test_root(name: &str) -> PathBuf {
+    let root = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    if root.exists() {
+        fs::remove_dir_all(&root).unwrap();
+    }
+    fs::create_dir_all(&root).unwrap();
+    root
+}
+
+#[cfg(unix)]
+#[test]
+fn template_commands_need_yes_without_a_terminal() {
+    let root = test_root("hooks-gating");
+    let template = template(&root, r#"[{ copy = ".env.example", to = ".env" }, { run = "touch ran" }]"#);
+    let template = template.to_str().unwrap();
+
+    let output = fluxor_new(&root, &["asked", "--template", template]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(root.join("asked/.env").exists());
+    assert!(!root.join("asked/ran").exists());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("pass --yes to allow them"));
+
+    let output = fluxor_new(&root, &["allowed", "--template", template, "--yes"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(root.join("allowed/.env").exists());
+    assert!(root.join("allowed/ran").exists());
+
+    let output = fluxor_new(&root, &["skipped", "--template", template, "--no-hooks"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(!root.join("skipped/.env").exists());
+    assert!(!root.join("skipped/ran").exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn failing_commands_keep_the_project() {
+    let root = test_root("hooks-failing");
+    let template = template(&root, r#"[{ run = "exit 3" }]"#);
+
+    let output = fluxor_new(&root, &["failed", "--template", template.to_str().unwrap(), "--yes"]);
+    assert_eq!(output.status.code(), Some(20));
+    assert!(root.join("failed/Cargo.toml").exists());
+}
+
+#[test]
+fn git_init_is_skipped_without_git() {
+    let root = test_root("hooks-no-git");
+    let template = template(&root, r#"["git-init"]"#);
+    let empty_path = root.join("bin");
+    fs::create_dir_all(&empty_path).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_fluxor"))
+        .current_dir(&root)
+        .env("PATH", &empty_path)
+        .args(["new", "no_git", "--template", template.to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Skipped `git init`: git was not found."));
+    assert!(!root.join("no_git/.git").exists());
+}